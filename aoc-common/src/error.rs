@@ -0,0 +1,145 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    process,
+};
+
+/// A 1-based position in the puzzle input, as an editor would show it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl InputLocation {
+    /// From the 0-based indexes that `enumerate` hands out
+    pub fn new(line_index: usize, column_index: usize) -> Self {
+        Self {
+            line: line_index + 1,
+            column: column_index + 1,
+        }
+    }
+
+    /// Where `part` starts inside `text`. `part` has to be a slice of `text`,
+    /// like the pieces `lines`, `split` and `trim` give back
+    pub fn of(text: &str, part: &str) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|&offset| offset <= text.len())
+            .expect("part should be a slice of text");
+        let before = &text[..offset];
+        let line_index = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Self::new(line_index, before[line_start..].chars().count())
+    }
+}
+
+impl Display for InputLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// A problem with the puzzle input and where it is. Each day has its own `K`ind of problems
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<K> {
+    pub location: InputLocation,
+    pub kind: K,
+}
+
+impl<K> ParseError<K> {
+    pub fn new(location: InputLocation, kind: K) -> Self {
+        Self { location, kind }
+    }
+
+    /// For errors from parsing a piece of the input on its own, like a single line,
+    /// moves them down by the `lines` that came before that piece
+    pub fn shifted(mut self, lines: usize) -> Self {
+        self.location.line += lines;
+        self
+    }
+
+    /// For errors from parsing `part` on its own, moves them to where `part` sits in `text`
+    pub fn within(mut self, text: &str, part: &str) -> Self {
+        let start = InputLocation::of(text, part);
+        if self.location.line == 1 {
+            self.location.column += start.column - 1;
+        }
+        self.location.line += start.line - 1;
+        self
+    }
+}
+
+impl<K: Display> Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)
+    }
+}
+
+impl<K: fmt::Debug + Display> Error for ParseError<K> {}
+
+/// Parses every line of `input` with `parse`, reporting errors on the line they came from
+pub fn parse_lines<T, K>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError<K>>,
+) -> Result<Vec<T>, ParseError<K>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.shifted(i)))
+        .collect()
+}
+
+/// For binaries: prints the error and exits instead of returning it
+pub fn or_exit<T, E: Display>(result: Result<T, E>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_of_slice() {
+        let text = "ab\ncd ef\ng";
+        let word = text.split_whitespace().nth(2).unwrap();
+
+        assert_eq!(
+            InputLocation::of(text, word),
+            InputLocation { line: 2, column: 4 }
+        );
+        assert_eq!(
+            InputLocation::of(text, &text[..0]),
+            InputLocation::new(0, 0)
+        );
+        assert_eq!(
+            InputLocation::of(text, &text[text.len()..]),
+            InputLocation::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn errors_shift_to_their_line() {
+        let result = parse_lines("1\n2\nx", |line| {
+            line.parse::<i32>()
+                .map_err(|_| ParseError::new(InputLocation::new(0, 0), "not a number"))
+        });
+
+        let err = result.unwrap_err();
+        assert_eq!(err.location, InputLocation { line: 3, column: 1 });
+        assert_eq!(err.to_string(), "line 3, column 1: not a number");
+    }
+
+    #[test]
+    fn errors_move_within_text() {
+        let text = "a: b\nc: d, e";
+        let part = text.split(", ").nth(1).unwrap();
+
+        let err = ParseError::new(InputLocation::new(0, 0), "bad").within(text, part);
+
+        assert_eq!(err.location, InputLocation { line: 2, column: 7 });
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable holding the path of the puzzle input
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+
+#[derive(Debug)]
+pub enum InputError {
+    NoInput,
+    File { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NoInput => write!(
+                f,
+                "no puzzle input: pass its path as the first argument, set {INPUT_ENV_VAR} to its path, or pipe it on stdin"
+            ),
+            InputError::File { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "could not read stdin: {source}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NoInput => None,
+            InputError::File { source, .. } => Some(source),
+            InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::File {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Picks the input from the first of `arg`, `env_path` and `stdin` that is present.
+/// An empty stdin counts as absent
+pub fn resolve<R: Read>(
+    arg: Option<PathBuf>,
+    env_path: Option<PathBuf>,
+    stdin: Option<R>,
+) -> Result<String, InputError> {
+    if let Some(path) = arg.or(env_path) {
+        return read_file(&path);
+    }
+    let mut input = String::new();
+    if let Some(mut stdin) = stdin {
+        stdin
+            .read_to_string(&mut input)
+            .map_err(InputError::Stdin)?;
+    }
+    if input.is_empty() {
+        Err(InputError::NoInput)
+    } else {
+        Ok(input)
+    }
+}
+
+/// Loads the input from the first command line argument, the `AOC_INPUT` environment
+/// variable, or stdin when it isn't a terminal, in that order
+pub fn load() -> Result<String, InputError> {
+    let stdin = io::stdin();
+    let piped_stdin = (!stdin.is_terminal()).then_some(stdin);
+    resolve(
+        env::args_os().nth(1).map(PathBuf::from),
+        env::var_os(INPUT_ENV_VAR).map(PathBuf::from),
+        piped_stdin,
+    )
+}
+
+/// [`load`] for binaries: prints the error and exits instead of returning it
+pub fn load_or_exit() -> String {
+    crate::or_exit(load())
+}
+
+/// Reads an optional input file, e.g. a personal `input.txt` that isn't checked in.
+/// Prefer the [`real_input!`](crate::real_input) macro in tests and benches
+pub fn read_optional(path: &str) -> Option<String> {
+    let input = fs::read_to_string(path).ok();
+    if input.is_none() {
+        eprintln!("skipping: no input at {path}");
+    }
+    input
+}
+
+/// The `input.txt` next to the calling crate's `Cargo.toml`, or `None` when it's missing
+/// so tests against a real input can skip themselves
+#[macro_export]
+macro_rules! real_input {
+    () => {
+        $crate::input::read_optional(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Cursor, Empty},
+        process,
+    };
+
+    use super::*;
+
+    fn temp_input(name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-common-{}-{name}", process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn argument_wins() {
+        let arg = temp_input("arg", "from arg");
+        let env_path = temp_input("env", "from env");
+
+        let input = resolve(Some(arg), Some(env_path), Some(Cursor::new("from stdin")));
+
+        assert_eq!(input.unwrap(), "from arg");
+    }
+
+    #[test]
+    fn env_before_stdin() {
+        let env_path = temp_input("env-only", "from env");
+
+        let input = resolve(None, Some(env_path), Some(Cursor::new("from stdin")));
+
+        assert_eq!(input.unwrap(), "from env");
+    }
+
+    #[test]
+    fn stdin_last() {
+        let input = resolve(None, None, Some(Cursor::new("from stdin")));
+
+        assert_eq!(input.unwrap(), "from stdin");
+    }
+
+    #[test]
+    fn nothing_present() {
+        let no_stdin = resolve::<Empty>(None, None, None);
+        let empty_stdin = resolve(None, None, Some(Cursor::new("")));
+
+        assert!(matches!(no_stdin, Err(InputError::NoInput)));
+        assert!(matches!(empty_stdin, Err(InputError::NoInput)));
+    }
+
+    #[test]
+    fn missing_file_names_path() {
+        let err = resolve::<Empty>(Some(PathBuf::from("nope/input.txt")), None, None).unwrap_err();
+
+        assert!(err.to_string().starts_with("could not read nope/input.txt"));
+    }
+
+    #[test]
+    fn real_input_skips_when_missing() {
+        assert_eq!(read_optional("definitely/not/here.txt"), None);
+    }
+}
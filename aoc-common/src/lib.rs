@@ -0,0 +1,6 @@
+mod error;
+pub mod input;
+pub mod parse;
+mod solution;
+pub use error::{or_exit, parse_lines, InputLocation, ParseError};
+pub use solution::{Answer, Solution};
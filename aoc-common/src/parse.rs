@@ -0,0 +1,259 @@
+//! The shapes puzzle inputs keep coming in: lists of numbers, blank line separated
+//! sections, `Name: value` headers and grids of chars.
+//!
+//! Errors point into the `s` each function is given. Callers that hand over a piece
+//! of their input move them back with [`ParseError::within`]. Each day's `ErrorKind`
+//! takes the problems here with a `From` impl, so it keeps its own wording for them.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{parse_lines, InputLocation, ParseError};
+
+/// A token that should have been a number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidNumber(pub String);
+
+impl Display for InvalidNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} isn't a number", self.0)
+    }
+}
+
+/// A separator that wasn't there, like the `:` after a card's name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Missing(pub char);
+
+impl Display for Missing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing {:?}", self.0)
+    }
+}
+
+/// A fixed size list, like a 3D position, with the wrong number of items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrongCount {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for WrongCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} values, but found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+/// Parses `token`, which has to be a slice of `s`
+pub fn number<T: FromStr, K: From<InvalidNumber>>(
+    s: &str,
+    token: &str,
+) -> Result<T, ParseError<K>> {
+    token.parse().map_err(|_| {
+        ParseError::new(
+            InputLocation::of(s, token),
+            InvalidNumber(token.to_string()).into(),
+        )
+    })
+}
+
+/// Whitespace separated numbers, like `79 14 55 13`
+pub fn numbers<T: FromStr, K: From<InvalidNumber>>(s: &str) -> Result<Vec<T>, ParseError<K>> {
+    s.split_whitespace().map(|token| number(s, token)).collect()
+}
+
+/// Numbers between `separator`s, with any spaces around them, like `19, 13, 30`
+pub fn separated<T: FromStr, K: From<InvalidNumber>>(
+    s: &str,
+    separator: char,
+) -> Result<Vec<T>, ParseError<K>> {
+    s.split(separator)
+        .map(|token| number(s, token.trim()))
+        .collect()
+}
+
+/// Exactly `N` of the `tokens` from `s`, without collecting them anywhere
+pub fn exactly<'a, const N: usize, K: From<WrongCount>>(
+    s: &str,
+    tokens: impl IntoIterator<Item = &'a str>,
+) -> Result<[&'a str; N], ParseError<K>> {
+    let mut found = [""; N];
+    let mut count = 0;
+    for token in tokens {
+        if let Some(slot) = found.get_mut(count) {
+            *slot = token;
+        }
+        count += 1;
+    }
+    if count != N {
+        let wrong = WrongCount {
+            expected: N,
+            found: count,
+        };
+        return Err(ParseError::new(InputLocation::of(s, s), wrong.into()));
+    }
+    Ok(found)
+}
+
+/// What comes before and after the first `separator`, like the two sides of `41 48 | 83 86`
+pub fn split_once<K: From<Missing>>(
+    s: &str,
+    separator: char,
+) -> Result<(&str, &str), ParseError<K>> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::new(InputLocation::of(s, s), Missing(separator).into()))
+}
+
+/// The trimmed name and the rest of a line like `Time:      7  15   30`
+pub fn header<K: From<Missing>>(line: &str) -> Result<(&str, &str), ParseError<K>> {
+    let (name, value) = split_once(line, ':')?;
+    Ok((name.trim(), value))
+}
+
+/// The blank line separated sections of `s`
+pub fn sections(s: &str) -> impl Iterator<Item = &str> {
+    s.split("\n\n")
+}
+
+/// The two sections of an input like a list of rules then a list of values.
+/// `missing` is what went wrong when there's no blank line, reported at the end of `s`
+pub fn two_sections<K>(s: &str, missing: K) -> Result<(&str, &str), ParseError<K>> {
+    s.split_once("\n\n")
+        .ok_or_else(|| ParseError::new(InputLocation::of(s, &s[s.len()..]), missing))
+}
+
+/// Parses every section of `s` with `parse`, reporting errors where they are in `s`
+pub fn parse_sections<T, K>(
+    s: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError<K>>,
+) -> Result<Vec<T>, ParseError<K>> {
+    sections(s)
+        .map(|section| parse(section).map_err(|err| err.within(s, section)))
+        .collect()
+}
+
+/// Parses each char of each line with `parse`. Rows can be different lengths,
+/// which is up to the caller
+pub fn char_rows<T, K>(
+    s: &str,
+    mut parse: impl FnMut(char) -> Result<T, K>,
+) -> Result<Vec<Vec<T>>, ParseError<K>> {
+    parse_lines(s, |line| {
+        line.chars()
+            .enumerate()
+            .map(|(col, c)| {
+                parse(c).map_err(|kind| ParseError::new(InputLocation::new(0, col), kind))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum ErrorKind {
+        InvalidNumber(String),
+        Missing(char),
+        WrongCount(usize),
+        Unknown(char),
+    }
+
+    impl From<InvalidNumber> for ErrorKind {
+        fn from(InvalidNumber(token): InvalidNumber) -> Self {
+            ErrorKind::InvalidNumber(token)
+        }
+    }
+
+    impl From<Missing> for ErrorKind {
+        fn from(Missing(separator): Missing) -> Self {
+            ErrorKind::Missing(separator)
+        }
+    }
+
+    impl From<WrongCount> for ErrorKind {
+        fn from(wrong: WrongCount) -> Self {
+            ErrorKind::WrongCount(wrong.found)
+        }
+    }
+
+    type Result<T> = std::result::Result<T, ParseError<ErrorKind>>;
+
+    #[test]
+    fn number_lists() {
+        let parsed: Result<Vec<u64>> = numbers(" 79 14  55 13");
+        assert_eq!(parsed, Ok(vec![79, 14, 55, 13]));
+        let parsed: Result<Vec<i64>> = separated("19, 13,-30", ',');
+        assert_eq!(parsed, Ok(vec![19, 13, -30]));
+
+        let err = numbers::<u8, ErrorKind>("1 2 x3").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(0, 4));
+        assert_eq!(err.kind, ErrorKind::InvalidNumber("x3".to_string()));
+        let err = separated::<i64, ErrorKind>("1, q, 3", ',').unwrap_err();
+        assert_eq!(err.location, InputLocation::new(0, 3));
+    }
+
+    #[test]
+    fn fixed_counts() {
+        let s = "50 98 2";
+        assert_eq!(
+            exactly::<3, ErrorKind>(s, s.split(' ')),
+            Ok(["50", "98", "2"])
+        );
+
+        let err: ParseError<ErrorKind> = exactly::<3, _>("52 50", "52 50".split(' ')).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::WrongCount(2));
+        let err: ParseError<ErrorKind> = exactly::<2, _>("1 2 3", "1 2 3".split(' ')).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::WrongCount(3));
+    }
+
+    #[test]
+    fn headers_and_separators() {
+        let line = "Card 1: 41 48 | 83 86";
+        let (name, rest): (&str, &str) = header::<ErrorKind>(line).unwrap();
+        assert_eq!(name, "Card 1");
+        let (winning, mine): (&str, &str) = split_once::<ErrorKind>(rest, '|').unwrap();
+        assert_eq!(numbers::<u8, ErrorKind>(winning), Ok(vec![41, 48]));
+        assert_eq!(numbers::<u8, ErrorKind>(mine), Ok(vec![83, 86]));
+
+        let missing: Result<(&str, &str)> = split_once("41 48", '|');
+        assert_eq!(missing.unwrap_err().kind, ErrorKind::Missing('|'));
+    }
+
+    #[test]
+    fn sections_report_where_they_are() {
+        let s = "1 2\n3\n\n4\n5 x";
+        let parsed: Result<Vec<Vec<u8>>> = parse_sections(s, numbers);
+        let err = parsed.unwrap_err();
+        assert_eq!(err.location, InputLocation::new(4, 2));
+
+        assert_eq!(
+            two_sections(s, ErrorKind::Unknown(' ')),
+            Ok(("1 2\n3", "4\n5 x"))
+        );
+        let err = two_sections("1 2\n3", ErrorKind::Unknown(' ')).unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 1));
+    }
+
+    #[test]
+    fn char_grids() {
+        let parse = |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            c => Err(ErrorKind::Unknown(c)),
+        };
+        assert_eq!(
+            char_rows(".#\n#", parse),
+            Ok(vec![vec![false, true], vec![true]])
+        );
+        let err = char_rows(".#\n#?", parse).unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 1));
+        assert_eq!(err.kind, ErrorKind::Unknown('?'));
+    }
+}
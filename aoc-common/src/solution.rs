@@ -0,0 +1,117 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// The result of a part, whatever integer type the day happened to compute it in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+/// A day of the puzzle: parse the input once, then solve either part from it
+pub trait Solution {
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts
+    type Input;
+    /// Anything besides the input that a part needs, like day 11's expansion factor.
+    /// `Default` gives the values for the real puzzle
+    type Config: Default;
+    type Output: Into<Answer>;
+    type Error: Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+    fn part1(input: &Self::Input, config: &Self::Config) -> Self::Output;
+    fn part2(input: &Self::Input, config: &Self::Config) -> Self::Output;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        const DAY: u8 = 0;
+        type Input = Vec<i64>;
+        type Config = ();
+        type Output = i64;
+        type Error = ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseIntError> {
+            input.lines().map(|line| line.parse()).collect()
+        }
+
+        fn part1(input: &Self::Input, _config: &()) -> i64 {
+            input.iter().sum::<i64>() * 2
+        }
+
+        fn part2(input: &Self::Input, _config: &()) -> i64 {
+            input.iter().product::<i64>() * 2
+        }
+    }
+
+    fn solve_generically<S: Solution>(input: &str) -> (Answer, Answer) {
+        let parsed = S::parse(input).unwrap();
+        let config = S::Config::default();
+        (
+            S::part1(&parsed, &config).into(),
+            S::part2(&parsed, &config).into(),
+        )
+    }
+
+    #[test]
+    fn generic_solve() {
+        let answers = solve_generically::<Doubler>("1\n-2\n3");
+        assert_eq!(answers, (Answer::Signed(4), Answer::Signed(-12)))
+    }
+
+    #[test]
+    fn parse_errors_surface() {
+        assert!(Doubler::parse("1\nfour").is_err());
+    }
+
+    #[test]
+    fn answers_display_as_plain_numbers() {
+        assert_eq!(Answer::from(-5_i64).to_string(), "-5");
+        assert_eq!(Answer::from(650672493820_u64).to_string(), "650672493820");
+        assert_eq!(Answer::from(7_u32), Answer::Unsigned(7));
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use aoc_common::{parse::char_rows, InputLocation, ParseError};
+
+use crate::Point;
+
+/// A row of a char map that isn't as wide as the first one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaggedRow {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for RaggedRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row is {} wide, but the first row is {} wide",
+            self.found, self.expected
+        )
+    }
+}
+
+/// A rectangle of cells stored row by row, indexed with `(row, col)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| f(row, col))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Panics unless every row is as wide as the first one
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "rows should all be {width} wide"
+        );
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a map with one cell per char, like most puzzle inputs.
+    /// Errors point at the offending char, or at where a ragged row stops matching the first
+    pub fn parse_chars<K: From<RaggedRow>>(
+        s: &str,
+        parse: impl FnMut(char) -> Result<T, K>,
+    ) -> Result<Self, ParseError<K>> {
+        let rows = char_rows(s, parse)?;
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((row, cells)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            let ragged = RaggedRow {
+                expected: width,
+                found: cells.len(),
+            };
+            return Err(ParseError::new(
+                InputLocation::new(row, width.min(cells.len())),
+                ragged.into(),
+            ));
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, row: impl TryInto<usize>, col: impl TryInto<usize>) -> Option<usize> {
+        let row = row.try_into().ok().filter(|&row| row < self.height)?;
+        let col = col.try_into().ok().filter(|&col| col < self.width)?;
+        Some(row * self.width + col)
+    }
+
+    /// `None` outside the grid, including for negative coordinates
+    pub fn get(&self, row: impl TryInto<usize>, col: impl TryInto<usize>) -> Option<&T> {
+        self.index_of(row, col).map(|index| &self.cells[index])
+    }
+
+    /// [`get`](Self::get) for a point, `None` when it's wandered off the grid
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.get(point.row, point.col)
+    }
+
+    pub fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.row, point.col)
+    }
+
+    pub fn get_mut(
+        &mut self,
+        row: impl TryInto<usize>,
+        col: impl TryInto<usize>,
+    ) -> Option<&mut T> {
+        self.index_of(row, col).map(|index| &mut self.cells[index])
+    }
+
+    /// Every cell in reading order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.cells.iter()
+    }
+
+    /// Every cell in reading order along with its `(row, col)`
+    pub fn indexed_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + ExactSizeIterator {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index / width, index % width), cell))
+    }
+
+    /// The `(row, col)` of the first cell in reading order that matches
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let index = self.cells.iter().position(predicate)?;
+        Some((index / self.width, index % self.width))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // Not `chunks`, which would give no rows at all for a grid without columns
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "column {col} is outside the grid");
+        // Not slicing from `col`, which is past the end for a grid without rows
+        (0..self.height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// The in-bounds cells above, below, left and right of `(row, col)`
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(row, col, &[(-1, 0), (1, 0), (0, -1), (0, 1)])
+    }
+
+    /// The in-bounds cells around `(row, col)`, diagonals included
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(
+            row,
+            col,
+            &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbors(
+        &self,
+        row: usize,
+        col: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(row_offset, col_offset)| {
+            let row = row
+                .checked_add_signed(row_offset)
+                .filter(|&row| row < height)?;
+            let col = col
+                .checked_add_signed(col_offset)
+                .filter(|&col| col < width)?;
+            Some((row, col))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns, mirroring along the diagonal from the top left
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |row, col| self[(col, row)].clone())
+    }
+
+    /// Turns the grid a quarter to the right, so the first column becomes the first row, reversed
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |row, col| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter to the left, so the last column becomes the first row
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.height, self.width, |row, col| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside the grid"))
+    }
+}
+
+/// One line per row, with each cell's own `Display` side by side
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = r##"abc
+def"##;
+
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        Ragged(RaggedRow),
+        NotALetter(char),
+    }
+
+    impl From<RaggedRow> for Kind {
+        fn from(value: RaggedRow) -> Self {
+            Kind::Ragged(value)
+        }
+    }
+
+    fn letters(s: &str) -> Result<Grid<char>, ParseError<Kind>> {
+        Grid::parse_chars(s, |c| {
+            if c.is_ascii_lowercase() {
+                Ok(c)
+            } else {
+                Err(Kind::NotALetter(c))
+            }
+        })
+    }
+
+    #[test]
+    fn parse_sample() {
+        let grid = letters(SAMPLE_INPUT).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.row(0), ['a', 'b', 'c']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.to_string(), SAMPLE_INPUT);
+    }
+
+    #[test]
+    fn parse_errors_have_locations() {
+        let bad_char = letters("abc\nd1f").unwrap_err();
+        let short_row = letters("abc\nab\nabc").unwrap_err();
+
+        assert_eq!(bad_char.location, InputLocation::new(1, 1));
+        assert_eq!(bad_char.kind, Kind::NotALetter('1'));
+        assert_eq!(short_row.location, InputLocation::new(1, 2));
+        assert_eq!(
+            short_row.kind,
+            Kind::Ragged(RaggedRow {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn signed_lookup_is_checked() {
+        let grid = letters(SAMPLE_INPUT).unwrap();
+
+        assert_eq!(grid.get(1_i32, 2_i32), Some(&'f'));
+        assert_eq!(grid.get(-1_i32, 0_i32), None);
+        assert_eq!(grid.get(0_i64, 3_i64), None);
+        assert_eq!(grid.get(2_usize, 0_usize), None);
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 2, 0);
+
+        let corner: Vec<_> = grid.neighbors4(0, 0).collect();
+        let middle_edge: Vec<_> = grid.neighbors8(1, 1).collect();
+
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        assert_eq!(middle_edge, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = letters(SAMPLE_INPUT).unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+    }
+
+    #[test]
+    fn empty_dimensions() {
+        let no_columns: Grid<char> = Grid::new(0, 3, '.');
+        assert_eq!(no_columns.rows().count(), 3);
+        assert!(no_columns.rows().all(|row| row.is_empty()));
+        assert_eq!(no_columns.columns().count(), 0);
+
+        let no_rows = no_columns.transpose();
+        assert_eq!((no_rows.width(), no_rows.height()), (3, 0));
+        assert_eq!(no_rows.rows().count(), 0);
+        assert_eq!(no_rows.column(2).count(), 0);
+        assert!(no_rows.columns().all(|mut column| column.next().is_none()));
+        assert_eq!(no_rows.transpose(), no_columns);
+    }
+}
@@ -0,0 +1,4 @@
+mod grid;
+mod point;
+pub use grid::{Grid, RaggedRow};
+pub use point::{Direction, Point};
@@ -0,0 +1,228 @@
+use std::ops::{Add, Sub};
+
+/// Which way to move on a grid. Up is towards row 0, left is towards column 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// North, east, south or west, with north being up
+    pub fn from_compass(letter: char) -> Option<Self> {
+        Some(match letter {
+            'N' => Direction::Up,
+            'E' => Direction::Right,
+            'S' => Direction::Down,
+            'W' => Direction::Left,
+            _ => return None,
+        })
+    }
+
+    /// The `U`, `D`, `L` and `R` some puzzles give their moves in
+    pub fn from_udlr(letter: char) -> Option<Self> {
+        Some(match letter {
+            'U' => Direction::Up,
+            'D' => Direction::Down,
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => return None,
+        })
+    }
+
+    /// Turns a quarter counterclockwise, as if walking in this direction and turning left
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// Turns a quarter clockwise
+    pub fn turn_right(self) -> Self {
+        self.turn_left().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// How a single step in this direction moves a point
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::Down => Point::new(1, 0),
+            Direction::Left => Point::new(0, -1),
+            Direction::Right => Point::new(0, 1),
+        }
+    }
+}
+
+/// A `(row, col)` that can wander off the grid, unlike the indexes a [`Grid`](crate::Grid) takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    pub const fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    /// `None` instead of overflowing
+    pub fn checked_add(self, other: Point) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add(other.row)?,
+            col: self.col.checked_add(other.col)?,
+        })
+    }
+
+    /// `None` instead of overflowing
+    pub fn checked_sub(self, other: Point) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_sub(other.row)?,
+            col: self.col.checked_sub(other.col)?,
+        })
+    }
+
+    /// `None` instead of overflowing
+    pub fn checked_step_by(self, direction: Direction, distance: i64) -> Option<Self> {
+        let offset = direction.offset();
+        self.checked_add(Point::new(
+            offset.row.checked_mul(distance)?,
+            offset.col.checked_mul(distance)?,
+        ))
+    }
+
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        self.checked_add(direction.offset())
+    }
+
+    /// Panics on overflow, which no puzzle gets near
+    pub fn step_by(self, direction: Direction, distance: i64) -> Self {
+        self.checked_step_by(direction, distance)
+            .expect("point overflowed")
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self.step_by(direction, 1)
+    }
+
+    /// The points above, below, left and right, in [`Direction::ALL`] order
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        self.checked_add(other).expect("point overflowed")
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        self.checked_sub(other).expect("point overflowed")
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self::new(
+            row.try_into().expect("row should fit in an i64"),
+            col.try_into().expect("col should fit in an i64"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_come_back_around() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(
+                direction.turn_left().turn_left().turn_left().turn_left(),
+                direction
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Down.turn_left(), Direction::Right);
+    }
+
+    #[test]
+    fn letters() {
+        let compass: Vec<_> = "NESW".chars().map(Direction::from_compass).collect();
+        let udlr: Vec<_> = "URDL".chars().map(Direction::from_udlr).collect();
+
+        assert_eq!(compass, Direction::ALL.map(Some));
+        assert_eq!(udlr, Direction::ALL.map(Some));
+        assert_eq!(Direction::from_compass('U'), None);
+        assert_eq!(Direction::from_udlr('N'), None);
+    }
+
+    #[test]
+    fn stepping() {
+        let start = Point::new(2, 3);
+
+        assert_eq!(start.step(Direction::Up), Point::new(1, 3));
+        assert_eq!(start.step_by(Direction::Left, 5), Point::new(2, -2));
+        assert_eq!(
+            start
+                .step(Direction::Right)
+                .step(Direction::Right.reverse()),
+            start
+        );
+        assert_eq!(start.manhattan_distance(Point::new(-1, 5)), 5);
+    }
+
+    #[test]
+    fn overflow_is_checked() {
+        let edge = Point::new(i64::MAX, 0);
+
+        assert_eq!(edge.checked_step(Direction::Down), None);
+        assert_eq!(
+            edge.checked_step(Direction::Up),
+            Some(Point::new(i64::MAX - 1, 0))
+        );
+        assert_eq!(
+            Point::new(0, -2).checked_step_by(Direction::Left, i64::MAX),
+            None
+        );
+    }
+}
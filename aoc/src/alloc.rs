@@ -0,0 +1,150 @@
+//! Counts what the solutions allocate, when built with `--features alloc-stats`
+#![cfg_attr(not(feature = "alloc-stats"), allow(dead_code))]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting::new();
+
+/// What was allocated while measuring
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Memory {
+    /// Every byte asked for, including the ones freed again
+    pub allocated: usize,
+    /// The most that was in use at once, on top of what already was before
+    pub peak: usize,
+    pub allocations: usize,
+}
+
+/// The system allocator, keeping count as it goes. The counts are for the whole
+/// process, so anything running alongside a measurement ends up in it too
+pub struct Counting {
+    allocated: AtomicUsize,
+    allocations: AtomicUsize,
+    in_use: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Counting {
+    pub const fn new() -> Self {
+        Self {
+            allocated: AtomicUsize::new(0),
+            allocations: AtomicUsize::new(0),
+            in_use: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn record(&self, size: usize) {
+        self.allocated.fetch_add(size, Ordering::Relaxed);
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        let in_use = self.in_use.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(in_use, Ordering::Relaxed);
+    }
+
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Memory) {
+        let in_use = self.in_use.load(Ordering::Relaxed);
+        self.peak.store(in_use, Ordering::Relaxed);
+        let allocated = self.allocated.load(Ordering::Relaxed);
+        let allocations = self.allocations.load(Ordering::Relaxed);
+        let value = f();
+        let memory = Memory {
+            allocated: self.allocated.load(Ordering::Relaxed) - allocated,
+            peak: self.peak.load(Ordering::Relaxed).saturating_sub(in_use),
+            allocations: self.allocations.load(Ordering::Relaxed) - allocations,
+        };
+        (value, memory)
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.in_use.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counts as a new allocation of `new_size`, with the old one freed
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.in_use.fetch_sub(layout.size(), Ordering::Relaxed);
+            self.record(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f`, with what it allocated when the counting allocator is in use
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (value, memory) = ALLOCATOR.measure(f);
+        (value, Some(memory))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    (f(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_and_peak() {
+        let counting = Counting::new();
+        let small = Layout::array::<u64>(4).unwrap();
+        let big = Layout::array::<u64>(16).unwrap();
+
+        let ((), memory) = counting.measure(|| unsafe {
+            let first = counting.alloc(small);
+            counting.dealloc(first, small);
+            let second = counting.alloc(small);
+            let grown = counting.realloc(second, small, big.size());
+            counting.dealloc(grown, big);
+        });
+
+        assert_eq!(
+            memory,
+            Memory {
+                allocated: 32 + 32 + 128,
+                peak: 128,
+                allocations: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn peak_is_on_top_of_what_was_in_use() {
+        let counting = Counting::new();
+        let layout = Layout::array::<u8>(100).unwrap();
+        let held = unsafe { counting.alloc(layout) };
+
+        let ((), memory) = counting.measure(|| unsafe {
+            let ptr = counting.alloc(layout);
+            counting.dealloc(ptr, layout);
+        });
+
+        assert_eq!(memory.peak, 100);
+        unsafe { counting.dealloc(held, layout) };
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+/// Numbers for most answers, strings for the ones TOML integers can't hold
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+/// Known-good answers for someone's inputs, from a TOML file with a table per day:
+///
+/// ```toml
+/// [day9]
+/// part1 = 1861775706
+/// part2 = 1082
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let days: BTreeMap<String, DayAnswers> =
+            toml::from_str(s).map_err(|err| err.to_string())?;
+        let mut answers = BTreeMap::new();
+        for (key, day_answers) in days {
+            let day: u8 = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("table {key:?} should be named like \"day9\""))?;
+            for (part, expected) in [(1, day_answers.part1), (2, day_answers.part2)] {
+                let expected = match expected {
+                    Some(Expected::Number(number)) => number.to_string(),
+                    Some(Expected::Text(text)) => text,
+                    None => continue,
+                };
+                answers.insert((day, part), expected);
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+        Self::parse(&contents).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// `answers.toml` at the workspace root, next to the days
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_and_strings() {
+        let answers = Answers::parse(
+            r##"[day9]
+part1 = 1861775706
+part2 = "1082"
+
+[day20]
+part1 = 856482136"##,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(9, 1), Some("1861775706"));
+        assert_eq!(answers.get(9, 2), Some("1082"));
+        assert_eq!(answers.get(20, 1), Some("856482136"));
+        assert_eq!(answers.get(20, 2), None);
+    }
+
+    #[test]
+    fn bad_day_name() {
+        let err = Answers::parse("[nine]\npart1 = 3").unwrap_err();
+
+        assert_eq!(err, "table \"nine\" should be named like \"day9\"");
+    }
+
+    #[test]
+    fn unknown_part() {
+        assert!(Answers::parse("[day9]\npart3 = 3").is_err());
+    }
+}
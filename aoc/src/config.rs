@@ -0,0 +1,81 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+/// Environment variable that overrides the session token in the config file
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Per-user settings, from a TOML file like:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// ```
+///
+/// The session token is the `session` cookie from a logged in browser
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+}
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        toml::from_str(s).map_err(|err| err.to_string())
+    }
+
+    /// A missing file is the same as an empty one
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|err| format!("{}: {err}", path.display()))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    /// [`load`](Self::load)s the default file, with `AOC_SESSION` taking precedence
+    pub fn load_default() -> Result<Self, String> {
+        let mut config = match default_path() {
+            Some(path) => Self::load(&path)?,
+            None => Self::default(),
+        };
+        if let Some(session) = env::var(SESSION_ENV_VAR).ok().filter(|s| !s.is_empty()) {
+            config.session = Some(session);
+        }
+        Ok(config)
+    }
+}
+
+/// `aoc2023/config.toml` in the user's config directory, e.g. `~/.config` on Linux
+pub fn default_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("aoc2023").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_is_optional() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert_eq!(
+            Config::parse("session = \"abc\"")
+                .unwrap()
+                .session
+                .as_deref(),
+            Some("abc")
+        );
+        assert!(Config::parse("sesion = \"abc\"").is_err());
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let config = Config::load(Path::new("does/not/exist.toml")).unwrap();
+
+        assert_eq!(config, Config::default());
+    }
+}
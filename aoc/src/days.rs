@@ -0,0 +1,147 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Answer, Solution};
+use rand::{rngs::StdRng, SeedableRng};
+use tracing::info_span;
+
+use crate::alloc::{self, Memory};
+
+/// A part's answer, with parsing timed apart from solving
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// What parsing and solving allocated, with the `alloc-stats` feature
+    pub memory: Option<Memory>,
+}
+
+impl Solved {
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time + self.solve_time
+    }
+}
+
+pub struct Day {
+    pub day: u8,
+    solver: fn(&str, u8) -> Result<Solved, String>,
+    /// The parts that can be run to completion on a real input
+    parts: &'static [u8],
+    /// Makes a random input, for the days that have a `generate` module
+    generator: Option<fn(&mut StdRng, usize) -> String>,
+}
+
+fn solve<S: Solution>(input: &str, part: u8) -> Result<Solved, String> {
+    let (solved, memory) = alloc::measure(|| solve_unmeasured::<S>(input, part));
+    Ok(Solved { memory, ..solved? })
+}
+
+fn solve_unmeasured<S: Solution>(input: &str, part: u8) -> Result<Solved, String> {
+    let start = Instant::now();
+    let parsed = info_span!("parse", day = S::DAY, part)
+        .in_scope(|| S::parse(input))
+        .map_err(|err| err.to_string())?;
+    let parse_time = start.elapsed();
+    let config = S::Config::default();
+    let start = Instant::now();
+    let answer = info_span!("solve", day = S::DAY, part).in_scope(|| match part {
+        1 => S::part1(&parsed, &config).into(),
+        _ => S::part2(&parsed, &config).into(),
+    });
+    Ok(Solved {
+        answer,
+        parse_time,
+        solve_time: start.elapsed(),
+        memory: None,
+    })
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solver: solve::<S>,
+            parts: &[1, 2],
+            generator: None,
+        }
+    }
+
+    const fn only_parts(self, parts: &'static [u8]) -> Self {
+        Self { parts, ..self }
+    }
+
+    const fn generator(self, generator: fn(&mut StdRng, usize) -> String) -> Self {
+        Self {
+            generator: Some(generator),
+            ..self
+        }
+    }
+
+    /// The same input for the same `seed`. `None` when the day has no generator
+    pub fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        let generator = self.generator?;
+        Some(generator(&mut StdRng::seed_from_u64(seed), size))
+    }
+
+    /// `None` when the part isn't runnable, an error when the input doesn't parse
+    pub fn solve(&self, input: &str, part: u8) -> Option<Result<Solved, String>> {
+        self.parts
+            .contains(&part)
+            .then(|| (self.solver)(input, part))
+    }
+
+    /// `dayN/input.txt` next to the day's `Cargo.toml`
+    pub fn default_input_path(&self) -> PathBuf {
+        day_dir(self.day).join("input.txt")
+    }
+}
+
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The `dayN` crate in the workspace, whether or not it's registered
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day{day}"))
+}
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    // The hands are only ranked with part 2's jokers
+    Day::new::<day7::Day7>().only_parts(&[2]),
+    // Walking every node at once never finishes on a real input
+    Day::new::<day8::Day8>().only_parts(&[1]),
+    Day::new::<day9::Day9>(),
+    Day::new::<day10::Day10>().generator(day10::generate::input),
+    Day::new::<day11::Day11>().generator(day11::generate::input),
+    Day::new::<day12::Day12>().generator(day12::generate::input),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>().generator(day14::generate::input),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>().generator(day16::generate::input),
+    // Part 2 isn't solved yet
+    Day::new::<day17::Day17>()
+        .only_parts(&[1])
+        .generator(day17::generate::input),
+    // Part 2 isn't solved yet
+    Day::new::<day18::Day18>().only_parts(&[1]),
+    Day::new::<day19::Day19>().generator(day19::generate::input),
+    // Pressing the button until `rx` gets a low pulse never finishes on a real input
+    Day::new::<day20::Day20>()
+        .only_parts(&[1])
+        .generator(day20::generate::input),
+    // Part 2 isn't solved yet
+    Day::new::<day24::Day24>().only_parts(&[1]),
+];
@@ -0,0 +1,251 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+/// What a part's description gives for trying it out by hand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartExample {
+    pub part: u8,
+    /// Index into [`PuzzlePage::samples`]
+    pub sample: usize,
+    pub answer: Option<String>,
+}
+
+/// The examples in a saved puzzle description, which has a part 2 once part 1 is solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePage {
+    /// Every `<pre><code>` block, in page order
+    pub samples: Vec<String>,
+    pub parts: Vec<PartExample>,
+}
+
+const ENTITIES: &[(&str, &str)] = &[
+    ("&lt;", "<"),
+    ("&gt;", ">"),
+    ("&quot;", "\""),
+    ("&#39;", "'"),
+    ("&apos;", "'"),
+    // Last, so `&amp;lt;` becomes `&lt;` rather than `<`
+    ("&amp;", "&"),
+];
+
+/// The text of some HTML, with tags dropped and entities decoded
+fn text_of(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..].split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    ENTITIES
+        .iter()
+        .fold(text, |text, (entity, c)| text.replace(entity, c))
+}
+
+/// The contents of each `open ... close` in `html`
+fn blocks<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    html.split(open)
+        .skip(1)
+        .filter_map(move |after| after.split_once(close).map(|(inside, _)| inside))
+}
+
+/// The answer is conventionally the last emphasised code in the description
+fn answer_in(article: &str) -> Option<String> {
+    blocks(article, "<code><em>", "</em></code>")
+        .last()
+        .map(text_of)
+}
+
+pub fn extract(html: &str) -> PuzzlePage {
+    let mut samples = vec![];
+    let mut parts: Vec<PartExample> = vec![];
+    for (index, article) in blocks(html, "<article class=\"day-desc\">", "</article>").enumerate() {
+        let first_sample = samples.len();
+        samples.extend(
+            blocks(article, "<pre><code>", "</code></pre>")
+                .map(|sample| text_of(sample).trim_end_matches('\n').to_string()),
+        );
+        let sample = if first_sample < samples.len() {
+            Some(first_sample)
+        } else {
+            // Part 2 usually reuses part 1's example
+            parts.last().map(|previous| previous.sample)
+        };
+        if let Some(sample) = sample {
+            parts.push(PartExample {
+                part: index as u8 + 1,
+                sample,
+                answer: answer_in(article),
+            });
+        }
+    }
+    PuzzlePage { samples, parts }
+}
+
+/// A raw string literal with enough `#`s that nothing in `s` ends it early
+fn raw_string(s: &str) -> String {
+    let hashes = (2..)
+        .map(|count| "#".repeat(count))
+        .find(|hashes| !s.contains(&format!("\"{hashes}")))
+        .expect("some number of hashes should do");
+    format!("r{hashes}\"{s}\"{hashes}")
+}
+
+fn sample_name(sample: usize) -> String {
+    match sample {
+        0 => "SAMPLE_INPUT".to_string(),
+        _ => format!("SAMPLE_INPUT_{}", sample + 1),
+    }
+}
+
+/// A `tests` module with a `test_given_N` for each part whose answer was found,
+/// in the same shape as the existing days' tests
+pub fn render_tests(page: &PuzzlePage) -> String {
+    let used: BTreeSet<usize> = page.parts.iter().map(|part| part.sample).collect();
+    let mut lines = vec![
+        "#[cfg(test)]".to_string(),
+        "mod tests {".to_string(),
+        "    use super::*;".to_string(),
+    ];
+    for &sample in &used {
+        lines.push(String::new());
+        lines.push(format!(
+            "    const {}: &str = {};",
+            sample_name(sample),
+            raw_string(&page.samples[sample])
+        ));
+    }
+    for part in &page.parts {
+        let Some(answer) = &part.answer else {
+            continue;
+        };
+        let expected = if answer.parse::<i64>().is_ok() {
+            answer.clone()
+        } else {
+            format!("{answer:?}.to_string()")
+        };
+        lines.push(String::new());
+        lines.push("    #[test]".to_string());
+        lines.push(format!("    fn test_given_{}() {{", part.part));
+        lines.push(format!(
+            "        assert_eq!(part{}({}), Ok({expected}))",
+            part.part,
+            sample_name(part.sample)
+        ));
+        lines.push("    }".to_string());
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+/// Writes each sample to `dir/sample_N.txt`, counting from 1
+pub fn write_samples(page: &PuzzlePage, dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+    for (index, sample) in page.samples.iter().enumerate() {
+        let path = dir.join(format!("sample_{}.txt", index + 1));
+        fs::write(&path, sample)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIVEN_PAGE: &str = r##"<main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2><p>For example:</p>
+<pre><code>0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+</code></pre>
+<p>Lists like <code>a &lt; b</code> and the next value <code><em>18</em></code>.</p>
+<p>Adding the new values together produces <code><em>114</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1861775706</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Doing this for the same example gives <code><em>2</em></code>.</p>
+</article>
+</main>"##;
+
+    #[test]
+    fn extracts_samples_and_answers() {
+        let page = extract(GIVEN_PAGE);
+
+        assert_eq!(
+            page.samples,
+            vec!["0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45"]
+        );
+        assert_eq!(
+            page.parts,
+            vec![
+                PartExample {
+                    part: 1,
+                    sample: 0,
+                    answer: Some("114".to_string())
+                },
+                PartExample {
+                    part: 2,
+                    sample: 0,
+                    answer: Some("2".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn part_2_reuses_part_1s_sample() {
+        let page = extract(
+            r##"<article class="day-desc"><p>For example:</p>
+<pre><code>1 2 3
+</code></pre>
+<p>Which works out like this:</p>
+<pre><code>1 -> 2 -> 3
+</code></pre>
+<p>The total is <code><em>6</em></code>.</p>
+</article>
+<article class="day-desc"><p>Now the same example gives <code><em>7</em></code>.</p>
+</article>"##,
+        );
+
+        assert_eq!(page.samples.len(), 2);
+        let samples: Vec<usize> = page.parts.iter().map(|part| part.sample).collect();
+        assert_eq!(samples, vec![0, 0]);
+        assert_eq!(render_tests(&page).matches("const SAMPLE_INPUT").count(), 1);
+    }
+
+    #[test]
+    fn decodes_and_strips_markup() {
+        assert_eq!(
+            text_of("<em>x</em> &lt;-&gt; &amp;lt; &quot;"),
+            "x <-> &lt; \""
+        );
+    }
+
+    #[test]
+    fn renders_tests_like_the_days() {
+        let tests = render_tests(&extract(GIVEN_PAGE));
+
+        assert_eq!(
+            tests,
+            r###"#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = r##"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"##;
+
+    #[test]
+    fn test_given_1() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(114))
+    }
+
+    #[test]
+    fn test_given_2() {
+        assert_eq!(part2(SAMPLE_INPUT), Ok(2))
+    }
+}"###
+        );
+        assert_eq!(raw_string("a\"##b"), "r###\"a\"##b\"###");
+    }
+}
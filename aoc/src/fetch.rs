@@ -0,0 +1,122 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::site::Site;
+
+/// Downloaded inputs, one file per day. Inputs never change, so anything here is final
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `aoc2023/inputs` in the user's cache directory, e.g. `~/.cache` on Linux
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("aoc2023").join("inputs"))
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day}.txt"))
+    }
+
+    pub fn get(&self, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(day)).ok()
+    }
+
+    pub fn store(&self, day: u8, input: &str) -> Result<(), String> {
+        let path = self.path(day);
+        fs::create_dir_all(&self.dir)
+            .and_then(|()| write_atomically(&path, input))
+            .map_err(|err| format!("could not cache {}: {err}", path.display()))
+    }
+}
+
+/// Writes beside `path` and renames over it, so a cached input is never half written
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let partial = path.with_extension("part");
+    fs::write(&partial, contents)?;
+    fs::rename(&partial, path)
+}
+
+/// Gets a day's input from the cache, or downloads it into the cache when it isn't there
+pub struct Fetcher {
+    site: Site,
+    cache: InputCache,
+}
+
+impl Fetcher {
+    pub fn new(site: Site, cache: InputCache) -> Self {
+        Self { site, cache }
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        if let Some(input) = self.cache.get(day) {
+            return Ok(input);
+        }
+        if !self.site.has_session() {
+            return Err("no input, and no session token to download it with".to_string());
+        }
+        let input = self.site.get(&format!("/2023/day/{day}/input"))?;
+        self.cache.store(day, &input)?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+    use crate::site::test_server::{serve_once, site};
+
+    fn temp_cache(name: &str) -> InputCache {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        InputCache::new(dir)
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let (base_url, server) = serve_once("200 OK", "0 3 6 9 12 15\n");
+        let fetcher = Fetcher::new(site(&base_url, Some("abc")), temp_cache("download"));
+
+        let first = fetcher.input(9).unwrap();
+        let request = server.join().unwrap();
+        // Nothing is listening any more, so this has to come from the cache
+        let second = fetcher.input(9).unwrap();
+
+        assert_eq!(first, "0 3 6 9 12 15\n");
+        assert_eq!(second, first);
+        assert!(request.starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn rejected_session_is_not_cached() {
+        let (base_url, server) = serve_once("400 Bad Request", "Puzzle inputs differ by user.");
+        let cache = temp_cache("rejected");
+        let path = cache.path(9);
+        let fetcher = Fetcher::new(site(&base_url, Some("stale")), cache);
+
+        let err = fetcher.input(9).unwrap_err();
+        server.join().unwrap();
+
+        assert_eq!(err, "the session token was rejected");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn no_session_no_request() {
+        // Nothing listens there, so a request would have failed differently
+        let fetcher = Fetcher::new(site("http://127.0.0.1:9", None), temp_cache("no-session"));
+
+        let err = fetcher.input(9).unwrap_err();
+
+        assert_eq!(err, "no input, and no session token to download it with");
+    }
+}
@@ -0,0 +1,735 @@
+mod alloc;
+mod answers;
+mod config;
+mod days;
+mod examples;
+mod fetch;
+mod output;
+mod scaffold;
+mod site;
+mod submit;
+
+use std::{
+    fs,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, SystemTime},
+};
+
+use alloc::Memory;
+use answers::Answers;
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use config::Config;
+use days::{Day, Solved};
+use fetch::{Fetcher, InputCache};
+use output::Format;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use site::{Site, UreqClient};
+use submit::{Judgement, Reply, Submitter};
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log to stderr at this level and above (error, warn, info, debug or trace).
+    /// Parsing and solving each get a span, closed with how long they took
+    #[arg(long, global = true, value_name = "LEVEL")]
+    log: Option<Level>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day, and print a timing table
+    Run(RunArgs),
+    /// Run every day with an input and check the answers against a known-good list
+    Verify(VerifyArgs),
+    /// Submit an answer for a part, by default the one its solution gives
+    Submit(SubmitArgs),
+    /// Save the examples from a saved puzzle page to `dayN/samples` and print tests for them
+    Examples(ExamplesArgs),
+    /// Generate a `dayN` crate and register it in the workspace and the runner
+    NewDay(NewDayArgs),
+    /// Print a random input for a day, to stress its solution with `run --input`
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("target").required(true)))]
+struct RunArgs {
+    /// The day to run
+    #[arg(long, group = "target", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Run every registered day, skipping the ones without an input that can't be downloaded
+    #[arg(long, group = "target")]
+    all: bool,
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file, defaults to `dayN/input.txt`, then a cached or downloaded copy
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Known-good answers, defaults to `answers.toml` at the workspace root
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Only verify this day
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Send this instead of solving for it
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct ExamplesArgs {
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// The puzzle's page, saved from the browser
+    #[arg(long)]
+    html: PathBuf,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Parse the input as a grid of chars instead of lines of numbers
+    #[arg(long)]
+    grid: bool,
+    /// Start the tests from the examples on this saved puzzle page
+    #[arg(long)]
+    html: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Roughly how big, e.g. the side of a grid or the number of lines. Each day's
+    /// `generate::input` says what it means there
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+    /// Makes the same input every time, defaults to a random seed that's printed to stderr
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Solved(Solved),
+    Failed(String),
+    Skipped(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PartResult {
+    day: u8,
+    part: u8,
+    outcome: Outcome,
+}
+
+fn run_day(day: &Day, input: &str, parts: &[u8]) -> Vec<PartResult> {
+    parts
+        .iter()
+        .map(|&part| {
+            let outcome = match day.solve(input, part) {
+                Some(Ok(solved)) => Outcome::Solved(solved),
+                Some(Err(err)) => Outcome::Failed(err),
+                None => Outcome::Skipped("not runnable".to_string()),
+            };
+            PartResult {
+                day: day.day,
+                part,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+fn skip_day(day: &Day, parts: &[u8], reason: &str) -> Vec<PartResult> {
+    parts
+        .iter()
+        .map(|&part| PartResult {
+            day: day.day,
+            part,
+            outcome: Outcome::Skipped(reason.to_string()),
+        })
+        .collect()
+}
+
+/// The day's own `input.txt` when there is one, otherwise a cached or downloaded copy
+fn load_input(day: &Day, fetcher: &Fetcher) -> Result<String, String> {
+    fs::read_to_string(day.default_input_path()).or_else(|_| fetcher.input(day.day))
+}
+
+fn run(args: &RunArgs, fetcher: &Fetcher) -> Result<Vec<PartResult>, String> {
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    match args.day {
+        Some(day_number) => {
+            let day = days::find(day_number)
+                .ok_or_else(|| format!("day {day_number} has no registered solution"))?;
+            let input = match &args.input {
+                Some(path) => fs::read_to_string(path)
+                    .map_err(|err| format!("could not read {}: {err}", path.display()))?,
+                None => load_input(day, fetcher)?,
+            };
+            Ok(run_day(day, &input, &parts))
+        }
+        None => {
+            let inputs: Vec<_> = days::DAYS
+                .iter()
+                .map(|day| (day, load_input(day, fetcher)))
+                .collect();
+            Ok(run_days(&inputs, &parts))
+        }
+    }
+}
+
+/// Runs the days one after another, or all at once with the `parallel` feature.
+/// The results are in the same order either way
+fn run_days(inputs: &[(&Day, Result<String, String>)], parts: &[u8]) -> Vec<PartResult> {
+    let run = |(day, input): &(&Day, Result<String, String>)| match input {
+        Ok(input) => run_day(day, input, parts),
+        Err(reason) => skip_day(day, parts, reason),
+    };
+    #[cfg(feature = "parallel")]
+    let results: Vec<Vec<PartResult>> = inputs.par_iter().map(run).collect();
+    #[cfg(not(feature = "parallel"))]
+    let results: Vec<Vec<PartResult>> = inputs.iter().map(run).collect();
+    results.concat()
+}
+
+/// Allocated, peak and allocation count columns, for a table of parts that were measured
+fn memory_columns(memory: Option<Memory>) -> String {
+    let (allocated, peak, allocations) = match memory {
+        Some(memory) => (
+            output::bytes(memory.allocated),
+            output::bytes(memory.peak),
+            memory.allocations.to_string(),
+        ),
+        None => ("-".to_string(), "-".to_string(), "-".to_string()),
+    };
+    format!(" {allocated:>10} {peak:>10} {allocations:>8}")
+}
+
+fn render_table(results: &[PartResult]) -> String {
+    let measured = results.iter().any(|result| {
+        matches!(
+            result.outcome,
+            Outcome::Solved(Solved {
+                memory: Some(_),
+                ..
+            })
+        )
+    });
+    let mut header = format!("{:>3} {:>4} {:>20} {:>14}", "Day", "Part", "Answer", "Time");
+    if measured {
+        header += &format!(" {:>10} {:>10} {:>8}", "Allocated", "Peak", "Allocs");
+    }
+    let mut lines = vec![header];
+    for result in results {
+        let (answer, time, memory) = match &result.outcome {
+            Outcome::Solved(solved) => (
+                solved.answer.to_string(),
+                format!("{:?}", solved.elapsed()),
+                solved.memory,
+            ),
+            Outcome::Failed(_) => ("failed".to_string(), "-".to_string(), None),
+            Outcome::Skipped(reason) => (format!("({reason})"), "-".to_string(), None),
+        };
+        let mut line = format!(
+            "{:>3} {:>4} {:>20} {:>14}",
+            result.day, result.part, answer, time
+        );
+        if measured {
+            line += &memory_columns(memory);
+        }
+        lines.push(line);
+    }
+    let total: Duration = results
+        .iter()
+        .filter_map(|result| match result.outcome {
+            Outcome::Solved(solved) => Some(solved.elapsed()),
+            Outcome::Failed(_) | Outcome::Skipped(_) => None,
+        })
+        .sum();
+    lines.push(format!("{:>44}", format!("Total {total:?}")));
+    for result in results {
+        if let Outcome::Failed(err) = &result.outcome {
+            lines.push(format!("day {} part {}: {err}", result.day, result.part));
+        }
+    }
+    lines.join("\n")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    Pass,
+    Fail,
+    /// Solved, but there's no known answer to check against
+    Unknown,
+    Skipped,
+}
+
+impl Verdict {
+    fn of(result: &PartResult, answers: &Answers) -> Self {
+        match &result.outcome {
+            Outcome::Solved(solved) => match answers.get(result.day, result.part) {
+                Some(expected) if expected == solved.answer.to_string() => Verdict::Pass,
+                Some(_) => Verdict::Fail,
+                None => Verdict::Unknown,
+            },
+            Outcome::Failed(_) => Verdict::Fail,
+            Outcome::Skipped(_) => Verdict::Skipped,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Unknown => "unknown",
+            Verdict::Skipped => "skipped",
+        }
+    }
+}
+
+fn verify(args: &VerifyArgs, fetcher: &Fetcher) -> Result<(Vec<PartResult>, Answers), String> {
+    let path = args.answers.clone().unwrap_or_else(answers::default_path);
+    let answers = Answers::load(&path)?;
+    let results = run(
+        &RunArgs {
+            day: args.day,
+            all: args.day.is_none(),
+            part: None,
+            input: None,
+            format: Format::Table,
+        },
+        fetcher,
+    )?;
+    Ok((results, answers))
+}
+
+fn render_verification(results: &[PartResult], answers: &Answers) -> String {
+    let mut lines = vec![format!(
+        "{:>3} {:>4} {:>20} {:>20} {:>8} {:>14}",
+        "Day", "Part", "Answer", "Expected", "Status", "Time"
+    )];
+    let verdicts: Vec<Verdict> = results
+        .iter()
+        .map(|result| Verdict::of(result, answers))
+        .collect();
+    for (result, verdict) in results.iter().zip(&verdicts) {
+        let (answer, time) = match &result.outcome {
+            Outcome::Solved(solved) => {
+                (solved.answer.to_string(), format!("{:?}", solved.elapsed()))
+            }
+            Outcome::Failed(_) => ("failed".to_string(), "-".to_string()),
+            Outcome::Skipped(reason) => (format!("({reason})"), "-".to_string()),
+        };
+        let expected = answers.get(result.day, result.part).unwrap_or("-");
+        lines.push(format!(
+            "{:>3} {:>4} {:>20} {:>20} {:>8} {:>14}",
+            result.day,
+            result.part,
+            answer,
+            expected,
+            verdict.label(),
+            time
+        ));
+    }
+    let count = |verdict| verdicts.iter().filter(|&&v| v == verdict).count();
+    lines.push(format!(
+        "{} passed, {} failed, {} unknown, {} skipped",
+        count(Verdict::Pass),
+        count(Verdict::Fail),
+        count(Verdict::Unknown),
+        count(Verdict::Skipped)
+    ));
+    for (result, verdict) in results.iter().zip(&verdicts) {
+        match &result.outcome {
+            Outcome::Solved(Solved { answer, .. }) if *verdict == Verdict::Fail => {
+                let expected = answers.get(result.day, result.part).unwrap_or_default();
+                lines.push(format!(
+                    "day {} part {}: expected {expected}, got {answer}",
+                    result.day, result.part
+                ));
+            }
+            Outcome::Failed(err) => {
+                lines.push(format!("day {} part {}: {err}", result.day, result.part));
+            }
+            _ => {}
+        }
+    }
+    lines.join("\n")
+}
+
+fn exit_code(failed: bool) -> ExitCode {
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The answer to send, solving the part on its input when one isn't given
+fn answer_to_submit(args: &SubmitArgs, fetcher: &Fetcher) -> Result<String, String> {
+    if let Some(answer) = &args.answer {
+        return Ok(answer.clone());
+    }
+    let day = days::find(args.day)
+        .ok_or_else(|| format!("day {} has no registered solution", args.day))?;
+    let input = load_input(day, fetcher)?;
+    match day.solve(&input, args.part) {
+        Some(solved) => Ok(solved?.answer.to_string()),
+        None => Err(format!(
+            "day {} part {} isn't runnable",
+            args.day, args.part
+        )),
+    }
+}
+
+fn submit(args: &SubmitArgs, fetcher: &Fetcher, submitter: &Submitter) -> Result<Reply, String> {
+    let answer = answer_to_submit(args, fetcher)?;
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("the clock should be past 1970")
+        .as_secs();
+    let reply = submitter.submit(args.day, args.part, &answer, now)?;
+    println!("day {} part {}: {answer} is {reply}", args.day, args.part);
+    Ok(reply)
+}
+
+fn scaffold_examples(args: &ExamplesArgs) -> Result<(), String> {
+    let html = fs::read_to_string(&args.html)
+        .map_err(|err| format!("could not read {}: {err}", args.html.display()))?;
+    let page = examples::extract(&html);
+    if page.samples.is_empty() {
+        return Err(format!("{} has no examples", args.html.display()));
+    }
+    examples::write_samples(&page, &days::day_dir(args.day).join("samples"))?;
+    println!("{}", examples::render_tests(&page));
+    Ok(())
+}
+
+fn new_day(args: &NewDayArgs) -> Result<(), String> {
+    let page = match &args.html {
+        Some(path) => {
+            let html = fs::read_to_string(path)
+                .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+            Some(examples::extract(&html))
+        }
+        None => None,
+    };
+    let new_day = scaffold::NewDay {
+        day: args.day,
+        grid: args.grid,
+        tests: page.as_ref().map(examples::render_tests),
+    };
+    new_day.generate(&days::workspace_dir())?;
+    if let Some(page) = &page {
+        examples::write_samples(page, &days::day_dir(args.day).join("samples"))?;
+    }
+    println!("created day{}", args.day);
+    Ok(())
+}
+
+fn generate(args: &GenerateArgs) -> Result<String, String> {
+    let day = days::find(args.day)
+        .ok_or_else(|| format!("day {} has no registered solution", args.day))?;
+    let seed = args.seed.unwrap_or_else(rand::random);
+    let input = day
+        .generate(seed, args.size)
+        .ok_or_else(|| format!("day {} has no input generator", args.day))?;
+    if args.seed.is_none() {
+        eprintln!("seed {seed}");
+    }
+    Ok(input)
+}
+
+fn site(config: &Config) -> Site {
+    Site::new(Box::new(UreqClient::default()), config.session.clone())
+}
+
+/// Downloads into the user's cache with the session token from their config
+fn fetcher(config: &Config) -> Result<Fetcher, String> {
+    let cache_dir =
+        InputCache::default_dir().ok_or("there's no cache directory to download inputs to")?;
+    Ok(Fetcher::new(site(config), InputCache::new(cache_dir)))
+}
+
+fn submitter(config: &Config) -> Result<Submitter, String> {
+    let log_path =
+        submit::default_log_path().ok_or("there's no data directory to log submissions in")?;
+    Ok(Submitter::new(site(config), log_path))
+}
+
+/// Logging stays off unless asked for, so the days' chatter doesn't get in the way of the results
+fn init_logging(level: Option<Level>) {
+    let Some(level) = level else {
+        return;
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.log);
+    let outcome = Config::load_default().and_then(|config| match cli.command {
+        Command::Run(args) => run(&args, &fetcher(&config)?).map(|results| {
+            let rendered = match args.format {
+                Format::Table => render_table(&results),
+                Format::Json => output::render_json_lines(&results),
+                Format::Csv => output::render_csv(&results),
+            };
+            println!("{rendered}");
+            results
+                .iter()
+                .any(|result| matches!(result.outcome, Outcome::Failed(_)))
+        }),
+        Command::Verify(args) => verify(&args, &fetcher(&config)?).map(|(results, answers)| {
+            println!("{}", render_verification(&results, &answers));
+            results
+                .iter()
+                .any(|result| Verdict::of(result, &answers) == Verdict::Fail)
+        }),
+        Command::Submit(args) => submit(&args, &fetcher(&config)?, &submitter(&config)?)
+            .map(|reply| reply != Reply::Judged(Judgement::Correct, None)),
+        Command::Examples(args) => scaffold_examples(&args).map(|()| false),
+        Command::NewDay(args) => new_day(&args).map(|()| false),
+        Command::Generate(args) => generate(&args).map(|input| {
+            println!("{input}");
+            false
+        }),
+    });
+    match outcome {
+        Ok(failed) => exit_code(failed),
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Answer;
+
+    use super::*;
+
+    const SAMPLE_DAY_9: &str = r##"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"##;
+
+    /// Can't download anything, and has nothing cached
+    fn offline_fetcher() -> Fetcher {
+        let cache = std::env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        Fetcher::new(
+            Site::new(Box::new(UreqClient::default()), None),
+            InputCache::new(cache),
+        )
+    }
+
+    #[test]
+    fn days_are_registered_in_order() {
+        assert!(days::DAYS.windows(2).all(|w| w[0].day < w[1].day));
+    }
+
+    #[test]
+    fn logging_is_off_unless_asked_for() {
+        let quiet = Cli::try_parse_from(["aoc", "run", "--all"]).unwrap();
+        let loud = Cli::try_parse_from(["aoc", "verify", "--log", "debug"]).unwrap();
+
+        assert_eq!(quiet.log, None);
+        assert_eq!(loud.log, Some(Level::DEBUG));
+    }
+
+    #[test]
+    fn run_day_sample() {
+        let day = days::find(9).unwrap();
+
+        let results = run_day(day, SAMPLE_DAY_9, &[1, 2]);
+
+        let answers: Vec<Option<Answer>> = results
+            .iter()
+            .map(|r| match r.outcome {
+                Outcome::Solved(solved) => Some(solved.answer),
+                Outcome::Failed(_) | Outcome::Skipped(_) => None,
+            })
+            .collect();
+        assert_eq!(
+            answers,
+            vec![Some(Answer::Signed(114)), Some(Answer::Signed(2))]
+        );
+    }
+
+    #[test]
+    fn unsolved_parts_are_skipped() {
+        for day in [17, 18, 20, 24] {
+            let day = days::find(day).unwrap();
+
+            let results = run_day(day, "", &[2]);
+
+            assert_eq!(
+                results[0].outcome,
+                Outcome::Skipped("not runnable".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn bad_input_fails_with_location() {
+        let day = days::find(9).unwrap();
+
+        let results = run_day(day, "0 3 6\n1 x 6", &[1]);
+
+        assert_eq!(
+            results[0].outcome,
+            Outcome::Failed("line 2, column 3: \"x\" isn't a number".to_string())
+        );
+    }
+
+    #[test]
+    fn generated_inputs_run() {
+        for day in days::DAYS {
+            let Some(input) = day.generate(day.day.into(), 10) else {
+                continue;
+            };
+
+            let results = run_day(day, &input, &[1]);
+
+            assert!(
+                matches!(results[0].outcome, Outcome::Solved(_)),
+                "day {}: {:?}",
+                day.day,
+                results[0].outcome
+            );
+            assert_eq!(day.generate(day.day.into(), 10), Some(input));
+        }
+    }
+
+    #[test]
+    fn days_run_in_order() {
+        let inputs: Vec<_> = days::DAYS
+            .iter()
+            .map(|day| match day.generate(0, 10) {
+                Some(input) => (day, Ok(input)),
+                None => (day, Err("no input".to_string())),
+            })
+            .collect();
+
+        let results = run_days(&inputs, &[1, 2]);
+
+        let order: Vec<(u8, u8)> = results.iter().map(|r| (r.day, r.part)).collect();
+        let expected: Vec<(u8, u8)> = days::DAYS
+            .iter()
+            .flat_map(|day| [(day.day, 1), (day.day, 2)])
+            .collect();
+        assert_eq!(order, expected);
+    }
+
+    #[test]
+    fn run_missing_input() {
+        let args = RunArgs {
+            day: Some(9),
+            all: false,
+            part: None,
+            input: Some(PathBuf::from("does/not/exist.txt")),
+            format: Format::Table,
+        };
+
+        assert!(run(&args, &offline_fetcher()).is_err());
+    }
+
+    #[test]
+    fn verdicts() {
+        let day = days::find(9).unwrap();
+        let answers = Answers::parse("[day9]\npart1 = 114\npart2 = 3").unwrap();
+
+        let results = run_day(day, SAMPLE_DAY_9, &[1, 2]);
+        let broken = run_day(day, "0 3 x", &[1]);
+        let verdicts: Vec<Verdict> = results
+            .iter()
+            .chain(&broken)
+            .map(|result| Verdict::of(result, &answers))
+            .collect();
+
+        assert_eq!(verdicts, vec![Verdict::Pass, Verdict::Fail, Verdict::Fail]);
+        assert_eq!(
+            Verdict::of(&results[0], &Answers::default()),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn verification_lists_mismatches() {
+        let day = days::find(9).unwrap();
+        let answers = Answers::parse("[day9]\npart1 = 114\npart2 = 3").unwrap();
+        let mut results = run_day(day, SAMPLE_DAY_9, &[1, 2]);
+        results.extend(skip_day(days::find(10).unwrap(), &[1], "no input"));
+
+        let report = render_verification(&results, &answers);
+
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].contains(" pass "));
+        assert!(lines[2].contains(" FAIL "));
+        assert!(lines[3].contains(" skipped "));
+        assert_eq!(lines[4], "1 passed, 1 failed, 0 unknown, 1 skipped");
+        assert_eq!(lines[5], "day 9 part 2: expected 3, got 2");
+    }
+
+    #[test]
+    fn table_shows_skipped_parts() {
+        let results = vec![
+            PartResult {
+                day: 9,
+                part: 1,
+                outcome: Outcome::Solved(Solved {
+                    answer: Answer::Signed(114),
+                    parse_time: Duration::from_millis(1),
+                    solve_time: Duration::from_millis(2),
+                    memory: None,
+                }),
+            },
+            PartResult {
+                day: 20,
+                part: 2,
+                outcome: Outcome::Skipped("not runnable".to_string()),
+            },
+        ];
+
+        let table = render_table(&results);
+
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].ends_with("114            3ms"));
+        assert!(lines[2].contains("(not runnable)"));
+        assert!(lines[3].ends_with("Total 3ms"));
+    }
+}
@@ -0,0 +1,227 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{Outcome, PartResult};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned columns for reading
+    #[default]
+    Table,
+    /// One JSON object per line
+    Json,
+    Csv,
+}
+
+/// A part's result flattened for other tools, with times in nanoseconds
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    parse_ns: Option<u64>,
+    solve_ns: Option<u64>,
+    /// Why the part failed or was skipped
+    detail: Option<&'a str>,
+    /// Only there with the `alloc-stats` feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocated_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<usize>,
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// A byte count in the biggest unit it has a whole one of, like `1.5 MiB`
+pub fn bytes(count: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if count < 1024 {
+        return format!("{count} B");
+    }
+    let mut size = count as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+impl<'a> From<&'a PartResult> for Record<'a> {
+    fn from(result: &'a PartResult) -> Self {
+        let record = Record {
+            day: result.day,
+            part: result.part,
+            status: "solved",
+            answer: None,
+            parse_ns: None,
+            solve_ns: None,
+            detail: None,
+            allocated_bytes: None,
+            peak_bytes: None,
+            allocations: None,
+        };
+        match &result.outcome {
+            Outcome::Solved(solved) => Record {
+                answer: Some(solved.answer.to_string()),
+                parse_ns: Some(nanos(solved.parse_time)),
+                solve_ns: Some(nanos(solved.solve_time)),
+                allocated_bytes: solved.memory.map(|memory| memory.allocated),
+                peak_bytes: solved.memory.map(|memory| memory.peak),
+                allocations: solved.memory.map(|memory| memory.allocations),
+                ..record
+            },
+            Outcome::Failed(err) => Record {
+                status: "failed",
+                detail: Some(err),
+                ..record
+            },
+            Outcome::Skipped(reason) => Record {
+                status: "skipped",
+                detail: Some(reason),
+                ..record
+            },
+        }
+    }
+}
+
+pub fn render_json_lines(results: &[PartResult]) -> String {
+    results
+        .iter()
+        .map(|result| {
+            serde_json::to_string(&Record::from(result)).expect("records should serialize")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quotes a field when it would otherwise break the row
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The memory columns are only there when some part was measured
+pub fn render_csv(results: &[PartResult]) -> String {
+    let records: Vec<Record> = results.iter().map(Record::from).collect();
+    let measured = records.iter().any(|record| record.allocations.is_some());
+    let mut header = "day,part,status,answer,parse_ns,solve_ns,detail".to_string();
+    if measured {
+        header += ",allocated_bytes,peak_bytes,allocations";
+    }
+    let mut lines = vec![header];
+    let optional = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+    for record in records {
+        let mut line = format!(
+            "{},{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            record.status,
+            record.answer.unwrap_or_default(),
+            optional(record.parse_ns),
+            optional(record.solve_ns),
+            csv_field(record.detail.unwrap_or_default())
+        );
+        if measured {
+            let optional = |value: Option<usize>| value.map(|v| v.to_string()).unwrap_or_default();
+            line += &format!(
+                ",{},{},{}",
+                optional(record.allocated_bytes),
+                optional(record.peak_bytes),
+                optional(record.allocations)
+            );
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Answer;
+
+    use super::*;
+    use crate::{alloc::Memory, days::Solved};
+
+    fn sample_results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                day: 9,
+                part: 1,
+                outcome: Outcome::Solved(Solved {
+                    answer: Answer::Signed(-114),
+                    parse_time: Duration::from_micros(2),
+                    solve_time: Duration::from_millis(3),
+                    memory: None,
+                }),
+            },
+            PartResult {
+                day: 9,
+                part: 2,
+                outcome: Outcome::Failed("line 2, column 3: \"x\" isn't a number".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn json_lines() {
+        let json = render_json_lines(&sample_results());
+
+        let lines: Vec<&str> = json.lines().collect();
+        assert_eq!(
+            lines[0],
+            r#"{"day":9,"part":1,"status":"solved","answer":"-114","parse_ns":2000,"solve_ns":3000000,"detail":null}"#
+        );
+        assert_eq!(
+            lines[1],
+            r#"{"day":9,"part":2,"status":"failed","answer":null,"parse_ns":null,"solve_ns":null,"detail":"line 2, column 3: \"x\" isn't a number"}"#
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let csv = render_csv(&sample_results());
+
+        assert_eq!(
+            csv,
+            r#"day,part,status,answer,parse_ns,solve_ns,detail
+9,1,solved,-114,2000,3000000,
+9,2,failed,,,,"line 2, column 3: ""x"" isn't a number""#
+        );
+    }
+
+    #[test]
+    fn memory_when_measured() {
+        let mut results = sample_results();
+        if let Outcome::Solved(solved) = &mut results[0].outcome {
+            solved.memory = Some(Memory {
+                allocated: 3 << 20,
+                peak: 1536,
+                allocations: 12,
+            });
+        }
+
+        let json = render_json_lines(&results);
+        let csv = render_csv(&results);
+
+        assert!(json.lines().next().unwrap().ends_with(
+            r#""detail":null,"allocated_bytes":3145728,"peak_bytes":1536,"allocations":12}"#
+        ));
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with(",detail,allocated_bytes,peak_bytes,allocations"));
+        assert!(lines[1].ends_with(",3000000,,3145728,1536,12"));
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 << 20), "3.0 MiB");
+        assert_eq!(bytes(100), "100 B");
+    }
+}
@@ -0,0 +1,451 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Stands in for the day number in the templates
+const DAY: &str = "__DAY__";
+
+const CARGO_TOML: &str = r##"[package]
+name = "day__DAY__"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+__GRID_DEPENDENCY__
+[lib]
+name = "mylib"
+path = "src/lib.rs"
+
+[dev-dependencies]
+criterion = { version = "0.4", features = ["html_reports"] }
+
+[[bench]]
+name = "my_benchmark"
+harness = false
+"##;
+
+const MAIN_RS: &str = r##"use mylib::*;
+use std::time::Instant;
+
+fn main() {
+    let input = &aoc_common::input::load_or_exit();
+    let start1 = Instant::now();
+    let part1_val = aoc_common::or_exit(part1(input));
+    let time1 = start1.elapsed();
+    println!("Part 1: {} in {:?}", part1_val, time1);
+    let start2 = Instant::now();
+    let part2_val = aoc_common::or_exit(part2(input));
+    let time2 = start2.elapsed();
+    println!("Part 2: {} in {:?}", part2_val, time2);
+}
+"##;
+
+const BENCH_RS: &str = r##"use aoc_common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mylib::Day__DAY__;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
+    let parsed = Day__DAY__::parse(input).unwrap();
+    c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
+    c.bench_function("part 2", |b| b.iter(|| mylib::part2(black_box(input))));
+    c.bench_function("parsing the input", |b| {
+        b.iter(|| Day__DAY__::parse(black_box(input)))
+    });
+    c.bench_function("solving part 1", |b| {
+        b.iter(|| Day__DAY__::part1(black_box(&parsed), &()))
+    });
+    c.bench_function("solving part 2", |b| {
+        b.iter(|| Day__DAY__::part2(black_box(&parsed), &()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
+"##;
+
+/// Each line a list of numbers, like day 9
+const LINES_LIB_RS: &str = r##"use aoc_common::{parse, parse_lines, Solution};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidNumber(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+        }
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    const DAY: u8 = __DAY__;
+    type Input = Vec<Vec<i64>>;
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(s, parse::numbers)
+    }
+
+    fn part1(_lines: &Self::Input, _config: &()) -> i64 {
+        todo!()
+    }
+
+    fn part2(_lines: &Self::Input, _config: &()) -> i64 {
+        todo!()
+    }
+}
+"##;
+
+/// A map with a cell per char, like day 13
+const GRID_LIB_RS: &str = r##"use aoc_common::Solution;
+use aoc_grid::{Grid, RaggedRow};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownTile(char),
+    RaggedRow(RaggedRow),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownTile(c) => write!(f, "unknown tile {c:?}"),
+            ErrorKind::RaggedRow(ragged) => write!(f, "{ragged}"),
+        }
+    }
+}
+
+impl From<RaggedRow> for ErrorKind {
+    fn from(value: RaggedRow) -> Self {
+        ErrorKind::RaggedRow(value)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Rock,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            '.' => Tile::Empty,
+            '#' => Tile::Rock,
+            _ => return Err(ErrorKind::UnknownTile(value)),
+        })
+    }
+}
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    const DAY: u8 = __DAY__;
+    type Input = Grid<Tile>;
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(s, Tile::try_from)
+    }
+
+    fn part1(_grid: &Self::Input, _config: &()) -> i64 {
+        todo!()
+    }
+
+    fn part2(_grid: &Self::Input, _config: &()) -> i64 {
+        todo!()
+    }
+}
+"##;
+
+const PART_FNS: &str = r##"
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(Day__DAY__::part1(&Day__DAY__::parse(s)?, &()))
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(Day__DAY__::part2(&Day__DAY__::parse(s)?, &()))
+}
+"##;
+
+/// For when there's no puzzle page to take examples from
+const PLACEHOLDER_TESTS: &str = r###"#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = r##""##;
+
+    #[test]
+    #[ignore]
+    fn test_given_1() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(0))
+    }
+
+    #[test]
+    #[ignore]
+    fn test_given_2() {
+        assert_eq!(part2(SAMPLE_INPUT), Ok(0))
+    }
+}"###;
+
+pub struct NewDay {
+    pub day: u8,
+    /// Parse the input as a grid of chars, rather than lines of numbers
+    pub grid: bool,
+    /// A `tests` module to start with, like the one [`render_tests`](crate::examples::render_tests) gives
+    pub tests: Option<String>,
+}
+
+impl NewDay {
+    fn fill(&self, template: &str) -> String {
+        template.replace(DAY, &self.day.to_string())
+    }
+
+    fn files(&self) -> Vec<(PathBuf, String)> {
+        let grid_dependency = if self.grid {
+            "aoc-grid = { path = \"../aoc-grid\" }\n"
+        } else {
+            ""
+        };
+        let lib = if self.grid { GRID_LIB_RS } else { LINES_LIB_RS };
+        let tests = self.tests.as_deref().unwrap_or(PLACEHOLDER_TESTS);
+        vec![
+            (
+                PathBuf::from("Cargo.toml"),
+                self.fill(CARGO_TOML)
+                    .replace("__GRID_DEPENDENCY__", grid_dependency),
+            ),
+            (PathBuf::from("src/main.rs"), MAIN_RS.to_string()),
+            (
+                PathBuf::from("src/lib.rs"),
+                format!("{}{}\n{tests}\n", self.fill(lib), self.fill(PART_FNS)),
+            ),
+            (
+                PathBuf::from("benches/my_benchmark.rs"),
+                self.fill(BENCH_RS),
+            ),
+        ]
+    }
+
+    /// Writes `dayN` under `root`, then adds it to the workspace and the runner
+    pub fn generate(&self, root: &Path) -> Result<(), String> {
+        let dir = root.join(format!("day{}", self.day));
+        if dir.exists() {
+            return Err(format!("{} already exists", dir.display()));
+        }
+        // Check the registrations first, so a clash leaves nothing behind
+        let registrations = [
+            (root.join("Cargo.toml"), register_member as Registrar),
+            (root.join("aoc").join("Cargo.toml"), register_dependency),
+            (root.join("aoc").join("src").join("days.rs"), register_day),
+        ]
+        .into_iter()
+        .map(|(path, register)| {
+            let contents = fs::read_to_string(&path)
+                .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+            Ok((path, register(&contents, self.day)?))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+        for (path, contents) in self.files() {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().expect("files are inside the day"))
+                .and_then(|()| fs::write(&path, contents))
+                .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        }
+        for (path, contents) in registrations {
+            fs::write(&path, contents)
+                .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+type Registrar = fn(&str, u8) -> Result<String, String>;
+
+/// Adds `line` among the lines `day_of` recognises, keeping them in day order.
+/// A line goes above any comments on the day after it
+fn insert_in_day_order(
+    text: &str,
+    day: u8,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line.trim())?)))
+        .collect();
+    if days.iter().any(|&(_, existing)| existing == day) {
+        return Err(format!("day {day} is already registered"));
+    }
+    let index = match days.iter().find(|&&(_, existing)| existing > day) {
+        Some(&(mut index, _)) => {
+            while index > 0 && lines[index - 1].trim().starts_with("//") {
+                index -= 1;
+            }
+            index
+        }
+        None => {
+            let mut index = days.last().ok_or("there are no days to go next to")?.0 + 1;
+            // Past the rest of a registration that's wrapped onto more lines
+            while index < lines.len() && lines[index].trim().starts_with('.') {
+                index += 1;
+            }
+            index
+        }
+    };
+    lines.insert(index, line);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+fn day_number(s: &str) -> Option<u8> {
+    s.strip_prefix("day")?.parse().ok()
+}
+
+/// `"dayN",` in the workspace's members
+fn register_member(manifest: &str, day: u8) -> Result<String, String> {
+    insert_in_day_order(manifest, day, &format!("    \"day{day}\","), |line| {
+        day_number(line.strip_prefix('"')?.strip_suffix("\",")?)
+    })
+}
+
+/// `dayN = { ... }` in the runner's dependencies
+fn register_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let line = format!("day{day} = {{ package = \"day{day}\", path = \"../day{day}\" }}");
+    insert_in_day_order(manifest, day, &line, |line| {
+        day_number(line.split_once(" = ")?.0)
+    })
+}
+
+/// An entry in `DAYS`, with no parts to run until they're written
+fn register_day(days: &str, day: u8) -> Result<String, String> {
+    let line = format!("    Day::new::<day{day}::Day{day}>().only_parts(&[]),");
+    insert_in_day_order(days, day, &line, |line| {
+        day_number(line.strip_prefix("Day::new::<")?.split_once("::")?.0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIVEN_MEMBERS: &str = r##"[workspace]
+members = [
+    "aoc",
+    "day9",
+    "day24",
+]
+"##;
+
+    const GIVEN_DAYS: &str = r##"pub const DAYS: &[Day] = &[
+    Day::new::<day9::Day9>(),
+    // Never finishes
+    Day::new::<day20::Day20>().only_parts(&[1]),
+];
+"##;
+
+    #[test]
+    fn registers_in_day_order() {
+        assert_eq!(
+            register_member(GIVEN_MEMBERS, 21).unwrap(),
+            r##"[workspace]
+members = [
+    "aoc",
+    "day9",
+    "day21",
+    "day24",
+]
+"##
+        );
+        assert_eq!(
+            register_day(GIVEN_DAYS, 12).unwrap(),
+            r##"pub const DAYS: &[Day] = &[
+    Day::new::<day9::Day9>(),
+    Day::new::<day12::Day12>().only_parts(&[]),
+    // Never finishes
+    Day::new::<day20::Day20>().only_parts(&[1]),
+];
+"##
+        );
+        assert_eq!(
+            register_dependency("[dependencies]\nday9 = { package = \"day9\", path = \"../day9\" }\n", 25)
+                .unwrap(),
+            "[dependencies]\nday9 = { package = \"day9\", path = \"../day9\" }\nday25 = { package = \"day25\", path = \"../day25\" }\n"
+        );
+    }
+
+    #[test]
+    fn registers_after_a_wrapped_day() {
+        let days = "pub const DAYS: &[Day] = &[\n    Day::new::<day20::Day20>()\n        .only_parts(&[1]),\n];\n";
+
+        assert_eq!(
+            register_day(days, 21).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    Day::new::<day20::Day20>()\n        .only_parts(&[1]),\n    Day::new::<day21::Day21>().only_parts(&[]),\n];\n"
+        );
+    }
+
+    #[test]
+    fn refuses_a_registered_day() {
+        assert_eq!(
+            register_member(GIVEN_MEMBERS, 24),
+            Err("day 24 is already registered".to_string())
+        );
+    }
+
+    #[test]
+    fn grid_days_depend_on_the_grid() {
+        let files = |grid| {
+            NewDay {
+                day: 25,
+                grid,
+                tests: None,
+            }
+            .files()
+        };
+        let grid = files(true);
+        let lines = files(false);
+
+        assert!(grid[0]
+            .1
+            .contains("aoc-grid = { path = \"../aoc-grid\" }\n\n[lib]"));
+        assert!(lines[0]
+            .1
+            .contains("aoc-common = { path = \"../aoc-common\" }\n\n[lib]"));
+        assert!(grid[2].1.contains("Grid::parse_chars(s, Tile::try_from)"));
+        assert!(lines[2].1.contains("pub struct Day25;"));
+        assert!(lines[2].1.contains("const SAMPLE_INPUT: &str = r##\"\"##;"));
+    }
+}
@@ -0,0 +1,213 @@
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// A response from the puzzle site, whatever its status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// How requests get to the puzzle site, so tests can stand in for it.
+/// An `Err` only when there's no response at all, e.g. the connection failed
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String>;
+}
+
+pub struct UreqClient(ureq::Agent);
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self(
+            ureq::AgentBuilder::new()
+                .user_agent("github.com/ChrisMcD1/aoc2023 runner")
+                .build(),
+        )
+    }
+}
+
+impl UreqClient {
+    fn request(&self, method: &str, url: &str, session: &str) -> ureq::Request {
+        self.0
+            .request(method, url)
+            .set("Cookie", &format!("session={session}"))
+    }
+}
+
+/// Error statuses still have a body worth reading, so they aren't errors here
+fn into_response(
+    url: &str,
+    result: Result<ureq::Response, ureq::Error>,
+) -> Result<Response, String> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(err.to_string()),
+    };
+    let status = response.status();
+    let body = response
+        .into_string()
+        .map_err(|err| format!("could not read the response from {url}: {err}"))?;
+    Ok(Response { status, body })
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        into_response(url, self.request("GET", url, session).call())
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String> {
+        into_response(url, self.request("POST", url, session).send_form(form))
+    }
+}
+
+/// The puzzle site as one logged in user sees it
+pub struct Site {
+    client: Box<dyn HttpClient>,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Site {
+    pub fn new(client: Box<dyn HttpClient>, session: Option<String>) -> Self {
+        Self {
+            client,
+            base_url: BASE_URL.to_string(),
+            session,
+        }
+    }
+
+    /// Points at a stand-in for the puzzle site
+    #[cfg(test)]
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    pub fn has_session(&self) -> bool {
+        self.session.is_some()
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            format!(
+                "no session token: set {} or `session` in the config file",
+                crate::config::SESSION_ENV_VAR
+            )
+        })
+    }
+
+    /// The body of a successful response to a GET of `path`, e.g. `/2023/day/9/input`
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self.client.get(&url, self.session()?)?;
+        successful(&url, response)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self.client.post_form(&url, self.session()?, form)?;
+        successful(&url, response)
+    }
+}
+
+fn successful(url: &str, response: Response) -> Result<String, String> {
+    match response.status {
+        200 => Ok(response.body),
+        // The site answers a bad or expired cookie with either of these
+        400 | 500 => Err("the session token was rejected".to_string()),
+        404 => Err(format!(
+            "there's nothing at {url} yet, is the day unlocked?"
+        )),
+        status => {
+            let reason = response.body.lines().next().unwrap_or_default();
+            Err(format!("{url} answered {status}: {reason}"))
+        }
+    }
+}
+
+/// A stand-in for the puzzle site that answers one request on a local port
+#[cfg(test)]
+pub mod test_server {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    /// Answers a single request, and hands back everything that was sent
+    pub fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.strip_prefix("Content-Length: ") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base_url, server)
+    }
+
+    pub fn site(base_url: &str, session: Option<&str>) -> Site {
+        Site::new(Box::new(UreqClient::default()), session.map(str::to_string))
+            .with_base_url(base_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statuses() {
+        let response = |status| Response {
+            status,
+            body: "Not today\nmore".to_string(),
+        };
+
+        assert_eq!(successful("u", response(200)).unwrap(), "Not today\nmore");
+        assert_eq!(
+            successful("u", response(400)).unwrap_err(),
+            "the session token was rejected"
+        );
+        assert_eq!(
+            successful("u", response(503)).unwrap_err(),
+            "u answered 503: Not today"
+        );
+    }
+}
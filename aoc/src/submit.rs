@@ -0,0 +1,375 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::site::Site;
+
+/// How long the site makes you wait after a wrong answer when it doesn't say
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// What the site thought of a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Judgement {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way
+    Wrong,
+}
+
+impl Display for Judgement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Judgement::Correct => "correct",
+            Judgement::TooHigh => "too high",
+            Judgement::TooLow => "too low",
+            Judgement::Wrong => "wrong",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reply {
+    /// Wrong answers come with how long to wait before the next one
+    Judged(Judgement, Option<Duration>),
+    /// Submitted too soon after the last answer, so it wasn't looked at
+    TooSoon(Duration),
+    /// The part is already solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reply::Judged(judgement, None) => write!(f, "{judgement}"),
+            Reply::Judged(judgement, Some(wait)) => {
+                write!(
+                    f,
+                    "{judgement}, wait {}s before trying again",
+                    wait.as_secs()
+                )
+            }
+            Reply::TooSoon(wait) => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Reply::WrongLevel => write!(f, "that part is already solved or still locked"),
+        }
+    }
+}
+
+/// `34s` or `1m 5s`, as in "You have 1m 5s left to wait"
+fn parse_short_duration(s: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for token in s.split_whitespace() {
+        seconds += if let Some(minutes) = token.strip_suffix('m') {
+            minutes.parse::<u64>().ok()? * 60
+        } else {
+            token.strip_suffix('s')?.parse::<u64>().ok()?
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// `one minute` or `5 minutes`, as in "please wait 5 minutes before trying again"
+fn parse_spelled_duration(s: &str) -> Option<Duration> {
+    let (count, unit) = s.split_once(' ')?;
+    let count = match count {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    let seconds = match unit.trim_end_matches('s') {
+        "second" => count,
+        "minute" => count * 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(seconds))
+}
+
+/// The text between `start` and `end` in `s`
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = s.split_once(start)?;
+    rest.split_once(end).map(|(middle, _)| middle)
+}
+
+/// Reads the verdict out of the page the site answers a submission with
+pub fn parse_reply(page: &str) -> Result<Reply, String> {
+    if page.contains("That's the right answer") {
+        Ok(Reply::Judged(Judgement::Correct, None))
+    } else if page.contains("That's not the right answer") {
+        let judgement = if page.contains("your answer is too high") {
+            Judgement::TooHigh
+        } else if page.contains("your answer is too low") {
+            Judgement::TooLow
+        } else {
+            Judgement::Wrong
+        };
+        let wait = between(page, "wait ", " before trying again").and_then(parse_spelled_duration);
+        Ok(Reply::Judged(judgement, wait))
+    } else if page.contains("You gave an answer too recently") {
+        let wait = between(page, "You have ", " left to wait")
+            .and_then(parse_short_duration)
+            .unwrap_or(DEFAULT_COOLDOWN);
+        Ok(Reply::TooSoon(wait))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Ok(Reply::WrongLevel)
+    } else {
+        Err("couldn't find a verdict in the reply".to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub judgement: Judgement,
+    /// Seconds since the Unix epoch
+    pub at: u64,
+}
+
+/// Every answer that's been judged, and when the next one is allowed, kept in a TOML file
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubmissionLog {
+    /// Seconds since the Unix epoch
+    wait_until: Option<u64>,
+    #[serde(default)]
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// A missing file is an empty log
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                toml::from_str(&contents).map_err(|err| format!("{}: {err}", path.display()))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = toml::to_string(self).expect("the log should serialize");
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(path, contents))
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    /// Why `answer` shouldn't be sent at `now`, judging by the earlier answers
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        let earlier = || {
+            self.submissions
+                .iter()
+                .filter(move |s| s.day == day && s.part == part)
+        };
+        if let Some(correct) = earlier().find(|s| s.judgement == Judgement::Correct) {
+            return Err(format!(
+                "day {day} part {part} is already solved with {}",
+                correct.answer
+            ));
+        }
+        if let Some(repeat) = earlier().find(|s| s.answer == answer) {
+            return Err(format!(
+                "{answer} was already tried, and was {}",
+                repeat.judgement
+            ));
+        }
+        // Numeric answers can be ruled out by the bounds the wrong ones gave
+        if let Ok(number) = answer.parse::<i64>() {
+            for submission in earlier() {
+                let Ok(bound) = submission.answer.parse::<i64>() else {
+                    continue;
+                };
+                let out_of_bounds = match submission.judgement {
+                    Judgement::TooHigh => number >= bound,
+                    Judgement::TooLow => number <= bound,
+                    Judgement::Correct | Judgement::Wrong => false,
+                };
+                if out_of_bounds {
+                    return Err(format!(
+                        "{answer} can't be right, {bound} was already {}",
+                        submission.judgement
+                    ));
+                }
+            }
+        }
+        match self.wait_until {
+            Some(wait_until) if now < wait_until => Err(format!(
+                "wait {}s before submitting again",
+                wait_until - now
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, reply: Reply, now: u64) {
+        let wait = match reply {
+            Reply::Judged(judgement, wait) => {
+                self.submissions.push(Submission {
+                    day,
+                    part,
+                    answer: answer.to_string(),
+                    judgement,
+                    at: now,
+                });
+                match judgement {
+                    Judgement::Correct => None,
+                    _ => Some(wait.unwrap_or(DEFAULT_COOLDOWN)),
+                }
+            }
+            Reply::TooSoon(wait) => Some(wait),
+            Reply::WrongLevel => None,
+        };
+        self.wait_until = wait.map(|wait| now + wait.as_secs());
+    }
+}
+
+/// `aoc2023/submissions.toml` in the user's data directory, e.g. `~/.local/share` on Linux
+pub fn default_log_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("aoc2023").join("submissions.toml"))
+}
+
+/// Posts answers, unless the log already shows they're pointless or too soon
+pub struct Submitter {
+    site: Site,
+    log_path: PathBuf,
+}
+
+impl Submitter {
+    pub fn new(site: Site, log_path: PathBuf) -> Self {
+        Self { site, log_path }
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<Reply, String> {
+        let mut log = SubmissionLog::load(&self.log_path)?;
+        log.check(day, part, answer, now)?;
+        let page = self.site.post_form(
+            &format!("/2023/day/{day}/answer"),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        let reply = parse_reply(&page)?;
+        log.record(day, part, answer, reply, now);
+        log.save(&self.log_path)?;
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+    use crate::site::test_server::{serve_once, site};
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    fn temp_log(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-submit-{}-{name}.toml", process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn replies() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        let too_high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. [<a href=\"/2023/day/9\">Return to Day 9</a>]");
+        let wrong = page("That's not the right answer.  If you're stuck, please wait 5 minutes before trying again.");
+        let too_soon = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.");
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+
+        assert_eq!(
+            parse_reply(&correct),
+            Ok(Reply::Judged(Judgement::Correct, None))
+        );
+        assert_eq!(
+            parse_reply(&too_high),
+            Ok(Reply::Judged(
+                Judgement::TooHigh,
+                Some(Duration::from_secs(60))
+            ))
+        );
+        assert_eq!(
+            parse_reply(&wrong),
+            Ok(Reply::Judged(
+                Judgement::Wrong,
+                Some(Duration::from_secs(300))
+            ))
+        );
+        assert_eq!(
+            parse_reply(&too_soon),
+            Ok(Reply::TooSoon(Duration::from_secs(65)))
+        );
+        assert_eq!(parse_reply(&solved), Ok(Reply::WrongLevel));
+        assert!(parse_reply("<html></html>").is_err());
+    }
+
+    #[test]
+    fn log_rules_out_answers() {
+        let mut log = SubmissionLog::default();
+        log.record(9, 1, "100", Reply::Judged(Judgement::TooLow, None), 1000);
+
+        assert_eq!(
+            log.check(9, 1, "200", 1030),
+            Err("wait 30s before submitting again".to_string())
+        );
+        log.record(9, 1, "200", Reply::Judged(Judgement::TooHigh, None), 1060);
+
+        assert_eq!(log.check(9, 1, "150", 1200), Ok(()));
+        assert_eq!(log.check(9, 2, "100", 1200), Ok(()));
+        assert_eq!(
+            log.check(9, 1, "200", 1200),
+            Err("200 was already tried, and was too high".to_string())
+        );
+        assert_eq!(
+            log.check(9, 1, "250", 1200),
+            Err("250 can't be right, 200 was already too high".to_string())
+        );
+        assert_eq!(
+            log.check(9, 1, "50", 1200),
+            Err("50 can't be right, 100 was already too low".to_string())
+        );
+        log.record(9, 1, "150", Reply::Judged(Judgement::Correct, None), 1200);
+        assert_eq!(
+            log.check(9, 1, "151", 1200),
+            Err("day 9 part 1 is already solved with 150".to_string())
+        );
+    }
+
+    #[test]
+    fn submits_and_records() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            &page("That's not the right answer; your answer is too low.  Please wait one minute before trying again."),
+        );
+        let log_path = temp_log("records");
+        let submitter = Submitter::new(site(&base_url, Some("abc")), log_path.clone());
+
+        let reply = submitter.submit(9, 2, "1082", 5000).unwrap();
+        let request = server.join().unwrap();
+        // Refused from the log alone, as nothing is listening any more
+        let again = submitter.submit(9, 2, "1082", 6000);
+
+        assert_eq!(
+            reply,
+            Reply::Judged(Judgement::TooLow, Some(Duration::from_secs(60)))
+        );
+        assert!(request.starts_with("POST /2023/day/9/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.ends_with("level=2&answer=1082"));
+        assert_eq!(
+            again,
+            Err("1082 was already tried, and was too low".to_string())
+        );
+        let log = SubmissionLog::load(&log_path).unwrap();
+        assert_eq!(log.wait_until, Some(5060));
+    }
+}
@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mylib::{Matcher, WordMap};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
+    let words_to_int = WordMap::default();
+    c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
+    c.bench_function("part 2", |b| {
+        b.iter(|| mylib::part2(black_box(input), &words_to_int))
+    });
+    c.bench_function("digits per line", |b| {
+        b.iter(|| {
+            black_box(input)
+                .lines()
+                .map(mylib::line_to_int)
+                .collect::<Vec<_>>()
+        })
+    });
+    let matcher = Matcher::new(&words_to_int);
+    c.bench_function("digits and words per line", |b| {
+        b.iter(|| {
+            black_box(input)
+                .lines()
+                .map(|line| mylib::line_to_int_with_words(line, &matcher))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,255 @@
+pub mod matcher;
+pub mod report;
+pub mod vocabulary;
+
+use aoc_common::{parse_lines, InputLocation, Solution};
+use std::fmt::{self, Display};
+
+pub use matcher::{Match, Matcher, TokenKind};
+pub use vocabulary::{Locale, WordMap};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    NoDigit,
+    NoDigitOrWord,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NoDigit => write!(f, "the line has no digit"),
+            ErrorKind::NoDigitOrWord => write!(f, "the line has no digit or number word"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// The error for a line without a number sits at its start, there's no one character to blame
+fn missing_number(kind: ErrorKind) -> ParseError {
+    ParseError::new(InputLocation::new(0, 0), kind)
+}
+
+pub struct Day1;
+
+/// Both parts read the same lines, so a line only has to have a number for the
+/// part that reads it. Here one without counts for nothing, where [`part1`] and
+/// [`part2`] reject it
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+    type Config = WordMap;
+    type Output = u64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Vec<String>, ParseError> {
+        Ok(s.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Vec<String>, _words_to_int: &WordMap) -> u64 {
+        lines.iter().filter_map(|line| line_to_int(line)).sum()
+    }
+
+    fn part2(lines: &Vec<String>, words_to_int: &WordMap) -> u64 {
+        let matcher = Matcher::new(words_to_int);
+        lines
+            .iter()
+            .filter_map(|line| line_to_int_with_words(line, &matcher))
+            .sum()
+    }
+}
+
+pub fn part1(s: &str) -> Result<u64, ParseError> {
+    let values = parse_lines(s, |line| {
+        line_to_int(line).ok_or_else(|| missing_number(ErrorKind::NoDigit))
+    })?;
+    Ok(values.iter().sum())
+}
+
+pub fn part2(s: &str, words_to_int: &WordMap) -> Result<u64, ParseError> {
+    let matcher = Matcher::new(words_to_int);
+    let values = parse_lines(s, |line| {
+        line_to_int_with_words(line, &matcher)
+            .ok_or_else(|| missing_number(ErrorKind::NoDigitOrWord))
+    })?;
+    Ok(values.iter().sum())
+}
+
+/// How a line's value came about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Match,
+    pub last: Match,
+    pub value: u64,
+}
+
+/// The first and last digit or word in the line. Where a digit and a word start
+/// or end at the same place, the digit wins. A word for a bigger number gives the
+/// digits it would be written with, so `twenty` is a 2 at the start of a line and
+/// a 0 at the end
+pub fn calibrate(s: &str, matcher: &Matcher) -> Option<Calibration> {
+    let matches = matcher.find_all(s);
+    // Matches come in the order they end, longest first when they end together.
+    // Either way round, the shortest match at a place is the one these keep
+    let first = *matches.iter().min_by_key(|m| m.start)?;
+    let last = *matches.iter().max_by_key(|m| m.end)?;
+    let mut leading = first.value;
+    while leading >= 10 {
+        leading /= 10;
+    }
+    Some(Calibration {
+        first,
+        last,
+        value: leading * 10 + last.value % 10,
+    })
+}
+
+pub fn line_to_int_with_words(s: &str, matcher: &Matcher) -> Option<u64> {
+    calibrate(s, matcher).map(|calibration| calibration.value)
+}
+
+pub fn line_to_int(s: &str) -> Option<u64> {
+    let first_char = s.chars().find(|c| c.is_numeric())?;
+    let last_char = s.chars().rev().find(|c| c.is_numeric())?;
+
+    let number = format!("{}{}", first_char, last_char);
+    number.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::real_input;
+    use proptest::prelude::*;
+    #[test]
+    fn part_1() {
+        let words_to_int = WordMap::default();
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(52974));
+        assert_eq!(part2(&input, &words_to_int), Ok(53340));
+    }
+
+    #[test]
+    fn line_without_digit() {
+        let err = part1("1abc2\npqr3stu8vwx\ntrebuchet").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(2, 0));
+        assert_eq!(err.kind, ErrorKind::NoDigit);
+    }
+
+    #[test]
+    fn solution_skips_lines_without_numbers() {
+        let lines = Day1::parse("two1nine\neightwothree\nabc").unwrap();
+        let words_to_int = WordMap::default();
+        assert_eq!(Day1::part1(&lines, &words_to_int), 11);
+        assert_eq!(Day1::part2(&lines, &words_to_int), 29 + 83);
+    }
+
+    #[test]
+    fn words_next_to_wide_chars() {
+        let words_to_int = WordMap::default();
+        assert_eq!(part2("twoé", &words_to_int), Ok(22));
+        assert_eq!(part2("é3ninë", &words_to_int), Ok(33));
+    }
+
+    #[test]
+    fn bigger_number_words() {
+        let words_to_int: WordMap = "ten: 10\ntwenty: 20\nsix: 6".parse().unwrap();
+        assert_eq!(
+            part2("twentyabcsix\nxtenx\n7twenty", &words_to_int),
+            Ok(26 + 10 + 70)
+        );
+        let german = WordMap::for_locale(Locale::German);
+        assert_eq!(part2("zweifünfacht\nneun", &german), Ok(28 + 99));
+    }
+
+    /// Finds an integer corresponding to the matching function
+    fn find_int<F>(words_to_int: &WordMap, f: F) -> Option<u64>
+    where
+        F: Fn(&str) -> bool,
+    {
+        words_to_int
+            .words()
+            .find(|&(word, _)| f(word))
+            .map(|(_, int)| int)
+    }
+
+    /// looks at a string and returns the first char as a number if it is a number,
+    /// or the number value if it one of the words in `word_to_int`
+    fn parse_front_numeric_or_word(s: &str, words_to_int: &WordMap) -> Option<u64> {
+        let parsed_numeric: Option<u64> = s.chars().next()?.to_string().parse().ok();
+        parsed_numeric.or_else(|| find_int(words_to_int, |word| s.starts_with(word)))
+    }
+
+    fn get_first_with_words(s: &str, words_to_int: &WordMap) -> Option<u64> {
+        s.char_indices()
+            .find_map(|(i, _)| parse_front_numeric_or_word(&s[i..], words_to_int))
+    }
+
+    /// looks at a string and returns the first char as a number if it is a number,
+    /// or the number value if it one of the words in `word_to_int`
+    fn parse_last_numeric_or_word(s: &str, words_to_int: &WordMap) -> Option<u64> {
+        let parsed_numeric: Option<u64> = s.chars().last()?.to_string().parse::<u64>().ok();
+        parsed_numeric.or_else(|| find_int(words_to_int, |word| s.ends_with(word)))
+    }
+
+    fn get_last_with_words(s: &str, words_to_int: &WordMap) -> Option<u64> {
+        s.char_indices()
+            .rev()
+            .find_map(|(i, c)| parse_last_numeric_or_word(&s[..i + c.len_utf8()], words_to_int))
+    }
+
+    /// Scans from every position in turn, checking every word there
+    fn line_to_int_with_words_slow(s: &str, words_to_int: &WordMap) -> Option<u64> {
+        let first = get_first_with_words(s, words_to_int)?;
+        let first_char = first.to_string().chars().next()?;
+        let last_char = get_last_with_words(s, words_to_int)? % 10;
+
+        let number = format!("{}{}", first_char, last_char);
+        number.parse().ok()
+    }
+
+    /// Lines made of pieces of number words, so that words overlap and nearly match
+    fn line() -> impl Strategy<Value = String> {
+        const PIECES: &[&str] = &[
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "on", "tw",
+            "ei", "ght", "e", "n", "x", "1", "7", "0", "é",
+        ];
+        prop::collection::vec(prop::sample::select(PIECES), 0..12)
+            .prop_map(|pieces| pieces.concat())
+    }
+
+    proptest! {
+        #[test]
+        fn matcher_finds_what_scanning_does(line in line()) {
+            let words_to_int = WordMap::default();
+            let matcher = Matcher::new(&words_to_int);
+
+            prop_assert_eq!(
+                line_to_int_with_words(&line, &matcher),
+                line_to_int_with_words_slow(&line, &words_to_int)
+            );
+
+            let mut found: Vec<(usize, usize, u64)> = matcher
+                .find_all(&line)
+                .iter()
+                .map(|m| (m.start, m.end, m.value))
+                .collect();
+            found.sort();
+            let mut expected = vec![];
+            for (start, _) in line.char_indices() {
+                let rest = &line[start..];
+                let digit = rest.get(..1).and_then(|digit| digit.parse().ok());
+                let digit = digit.map(|value| (start, start + 1, value));
+                let words = words_to_int
+                    .words()
+                    .filter(|(word, _)| rest.starts_with(word))
+                    .map(|(word, value)| (start, start + word.len(), value));
+                expected.extend(digit.into_iter().chain(words));
+            }
+            expected.sort();
+            prop_assert_eq!(found, expected);
+        }
+    }
+}
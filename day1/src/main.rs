@@ -1,125 +1,38 @@
-use std::collections::HashMap;
+use mylib::*;
+use std::{env, fs, time::Instant};
 
-struct WordMap(HashMap<&'static str, u64>);
-impl WordMap {
-    /// Finds an integer corresponding to the matching function
-    pub fn find_int<F>(&self, f: F) -> Option<u64>
-    where
-        F: Fn(&str) -> bool,
-    {
-        self.0
-            .iter()
-            .find(|(word, _)| f(word))
-            .map(|(_, int)| int.to_owned())
-    }
-}
+/// Environment variable picking the number words for part 2: a locale like `de`,
+/// or the path of a vocabulary file. English when it isn't set
+const WORDS_ENV_VAR: &str = "DAY1_WORDS";
 
-fn main() {
-    println!("Hello, world!");
-    let words_to_int: WordMap = WordMap(HashMap::from([
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-    ]));
-    let input = include_str!("../input.txt");
-    let answer: u64 = input
-        .lines()
-        .map(line_to_int)
-        .collect::<Option<Vec<_>>>()
-        .unwrap()
-        .iter()
-        .sum();
-    println!("Part 1: {}", answer);
-    let answer2: u64 = input
-        .lines()
-        .map(|w| line_to_int_with_words(w, &words_to_int))
-        .collect::<Option<Vec<_>>>()
-        .unwrap()
-        .iter()
-        .sum();
-    println!("Part 2: {}", answer2);
-}
+/// Set to print what part 2 read on each line before the answers
+const REPORT_ENV_VAR: &str = "DAY1_REPORT";
 
-/// looks at a string and returns the first char as a number if it is a number,
-/// or the number value if it one of the words in `word_to_int`
-fn parse_front_numeric_or_word(s: &str, words_to_int: &WordMap) -> Option<u64> {
-    let parsed_numeric: Option<u64> = s.chars().next()?.to_string().parse().ok();
-    parsed_numeric.or_else(|| words_to_int.find_int(|word| s.starts_with(word)))
+fn words() -> Result<WordMap, String> {
+    let Ok(words) = env::var(WORDS_ENV_VAR) else {
+        return Ok(WordMap::default());
+    };
+    let unknown_locale = match words.parse() {
+        Ok(locale) => return Ok(WordMap::for_locale(locale)),
+        Err(unknown_locale) => unknown_locale,
+    };
+    let vocabulary = fs::read_to_string(&words)
+        .map_err(|err| format!("{unknown_locale}, and could not read it as a file: {err}"))?;
+    vocabulary.parse().map_err(|err| format!("{words}: {err}"))
 }
 
-fn get_first_with_words(s: &str, words_to_int: &WordMap) -> Option<u64> {
-    s.char_indices()
-        .find_map(|(i, _)| parse_front_numeric_or_word(&s[i..], words_to_int))
-}
-
-/// looks at a string and returns the first char as a number if it is a number,
-/// or the number value if it one of the words in `word_to_int`
-fn parse_last_numeric_or_word(s: &str, words_to_int: &WordMap) -> Option<u64> {
-    let parsed_numeric: Option<u64> = s.chars().last()?.to_string().parse::<u64>().ok();
-    parsed_numeric.or_else(|| words_to_int.find_int(|word| s.ends_with(word)))
-}
-
-fn get_last_with_words(s: &str, words_to_int: &WordMap) -> Option<u64> {
-    s.char_indices().find_map(|(i, _)| {
-        let last = s.len() - i;
-        parse_last_numeric_or_word(&s[..last], words_to_int)
-    })
-}
-
-fn line_to_int_with_words(s: &str, words_to_int: &WordMap) -> Option<u64> {
-    let first_char = get_first_with_words(s, words_to_int)?;
-    let last_char = get_last_with_words(s, words_to_int)?;
-
-    let number = format!("{}{}", first_char, last_char);
-    number.parse().ok()
-}
-
-fn line_to_int(s: &str) -> Option<u64> {
-    let first_char = s.chars().find(|c| c.is_numeric())?;
-    let last_char = s.chars().rev().find(|c| c.is_numeric())?;
-
-    let number = format!("{}{}", first_char, last_char);
-    number.parse().ok()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn part_1() -> () {
-        let words_to_int = WordMap(HashMap::from([
-            ("one", 1),
-            ("two", 2),
-            ("three", 3),
-            ("four", 4),
-            ("five", 5),
-            ("six", 6),
-            ("seven", 7),
-            ("eight", 8),
-            ("nine", 9),
-        ]));
-        let input = include_str!("../input.txt");
-        let answer: u64 = input
-            .lines()
-            .map(line_to_int)
-            .collect::<Option<Vec<_>>>()
-            .unwrap()
-            .iter()
-            .sum();
-        assert_eq!(answer, 52974);
-        let answer2: u64 = input
-            .lines()
-            .map(|w| line_to_int_with_words(w, &words_to_int))
-            .collect::<Option<Vec<_>>>()
-            .unwrap()
-            .iter()
-            .sum();
-        assert_eq!(answer2, 53340);
+fn main() {
+    let input = &aoc_common::input::load_or_exit();
+    let words_to_int = aoc_common::or_exit(words());
+    if env::var_os(REPORT_ENV_VAR).is_some() {
+        println!("{}", report::render(&report::report(input, &words_to_int)));
     }
+    let start1 = Instant::now();
+    let part1_val = aoc_common::or_exit(part1(input));
+    let time1 = start1.elapsed();
+    println!("Part 1: {} in {:?}", part1_val, time1);
+    let start2 = Instant::now();
+    let part2_val = aoc_common::or_exit(part2(input, &words_to_int));
+    let time2 = start2.elapsed();
+    println!("Part 2: {} in {:?}", part2_val, time2);
 }
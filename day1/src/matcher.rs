@@ -0,0 +1,177 @@
+//! Finds every digit and number word in a line in one pass, with an Aho–Corasick
+//! automaton over the line's bytes. Words are valid UTF-8 like the line, so a match
+//! can only start and end on a char boundary
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::WordMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Digit,
+    Word,
+}
+
+/// One digit or word found in a line, at `line[start..end]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub kind: TokenKind,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token {
+    len: usize,
+    kind: TokenKind,
+    value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matcher {
+    /// Where each state goes on each byte, 256 entries a state
+    transitions: Vec<usize>,
+    /// The token that ends at each state, when one does
+    tokens: Vec<Option<Token>>,
+    /// The next state down the failure links with a token, for the shorter
+    /// tokens ending at the same place
+    outputs: Vec<Option<usize>>,
+}
+
+impl Matcher {
+    pub fn new(words_to_int: &WordMap) -> Self {
+        let mut children: Vec<HashMap<u8, usize>> = vec![HashMap::new()];
+        let mut tokens = vec![None];
+        let digits = (0..10).map(|digit| (digit.to_string(), digit, TokenKind::Digit));
+        let words = words_to_int
+            .words()
+            .map(|(word, value)| (word.to_string(), value, TokenKind::Word));
+        for (pattern, value, kind) in digits.chain(words) {
+            let mut state = 0;
+            for &byte in pattern.as_bytes() {
+                state = match children[state].get(&byte) {
+                    Some(&next) => next,
+                    None => {
+                        let next = children.len();
+                        children.push(HashMap::new());
+                        tokens.push(None);
+                        children[state].insert(byte, next);
+                        next
+                    }
+                };
+            }
+            tokens[state] = Some(Token {
+                len: pattern.len(),
+                kind,
+                value,
+            });
+        }
+
+        // Breadth first, so a state's failure link is finished before the state is
+        let mut transitions = vec![0; children.len() * 256];
+        let mut failures = vec![0; children.len()];
+        let mut outputs = vec![None; children.len()];
+        let mut queue = VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            for byte in 0..=255u8 {
+                let fallback = transitions[failures[state] * 256 + byte as usize];
+                let next = match children[state].get(&byte) {
+                    Some(&child) => {
+                        let failure = if state == 0 { 0 } else { fallback };
+                        failures[child] = failure;
+                        outputs[child] = match tokens[failure] {
+                            Some(_) => Some(failure),
+                            None => outputs[failure],
+                        };
+                        queue.push_back(child);
+                        child
+                    }
+                    None if state == 0 => 0,
+                    None => fallback,
+                };
+                transitions[state * 256 + byte as usize] = next;
+            }
+        }
+        Self {
+            transitions,
+            tokens,
+            outputs,
+        }
+    }
+
+    /// Every match in `line`, overlapping ones included, in the order they end.
+    /// Matches ending at the same place come longest first
+    pub fn find_all(&self, line: &str) -> Vec<Match> {
+        let mut matches = vec![];
+        let mut state = 0;
+        for (i, &byte) in line.as_bytes().iter().enumerate() {
+            state = self.transitions[state * 256 + byte as usize];
+            let mut found = match self.tokens[state] {
+                Some(_) => Some(state),
+                None => self.outputs[state],
+            };
+            while let Some(at) = found {
+                let token = self.tokens[at].expect("outputs only point at tokens");
+                matches.push(Match {
+                    start: i + 1 - token.len,
+                    end: i + 1,
+                    kind: token.kind,
+                    value: token.value,
+                });
+                found = self.outputs[at];
+            }
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_matches() {
+        let matcher = Matcher::new(&WordMap::default());
+
+        let matches = matcher.find_all("eightwo3");
+
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    start: 0,
+                    end: 5,
+                    kind: TokenKind::Word,
+                    value: 8
+                },
+                Match {
+                    start: 4,
+                    end: 7,
+                    kind: TokenKind::Word,
+                    value: 2
+                },
+                Match {
+                    start: 7,
+                    end: 8,
+                    kind: TokenKind::Digit,
+                    value: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn shorter_words_inside_longer_ones() {
+        let words_to_int: WordMap = "seventy: 70\nent: 1\nnty: 2".parse().unwrap();
+        let matcher = Matcher::new(&words_to_int);
+
+        let spans: Vec<(usize, usize, u64)> = matcher
+            .find_all("xseventyé")
+            .iter()
+            .map(|m| (m.start, m.end, m.value))
+            .collect();
+
+        assert_eq!(spans, vec![(4, 7, 1), (1, 8, 70), (5, 8, 2)]);
+    }
+}
@@ -0,0 +1,129 @@
+//! What part 2 read on each line, to find the lines it got wrong
+
+use crate::{calibrate, Calibration, Match, Matcher, TokenKind, WordMap};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineReport<'a> {
+    pub line: &'a str,
+    /// `None` when the line has no digit or number word
+    pub calibration: Option<Calibration>,
+}
+
+pub fn report<'a>(s: &'a str, words_to_int: &WordMap) -> Vec<LineReport<'a>> {
+    let matcher = Matcher::new(words_to_int);
+    s.lines()
+        .map(|line| LineReport {
+            line,
+            calibration: calibrate(line, &matcher),
+        })
+        .collect()
+}
+
+/// The token and where it is, in 1-based columns like the parse errors
+fn describe(line: &str, token: &Match) -> String {
+    let kind = match token.kind {
+        TokenKind::Digit => "digit",
+        TokenKind::Word => "word",
+    };
+    let first = line[..token.start].chars().count() + 1;
+    let last = line[..token.end].chars().count();
+    let columns = if first == last {
+        format!("column {first}")
+    } else {
+        format!("columns {first}-{last}")
+    };
+    format!("{:?} ({kind}, {columns})", &line[token.start..token.end])
+}
+
+/// `^` under every char of the first and last tokens
+fn underline(line: &str, calibration: &Calibration) -> String {
+    let marked = |i: usize| {
+        [calibration.first, calibration.last]
+            .iter()
+            .any(|token| (token.start..token.end).contains(&i))
+    };
+    let underline: String = line
+        .char_indices()
+        .map(|(i, _)| if marked(i) { '^' } else { ' ' })
+        .collect();
+    underline.trim_end().to_string()
+}
+
+/// Each line with what was read from it, and the tokens marked underneath
+pub fn render(reports: &[LineReport]) -> String {
+    let mut lines = vec![];
+    for (i, report) in reports.iter().enumerate() {
+        match &report.calibration {
+            Some(calibration) => {
+                lines.push(format!(
+                    "line {}: {} from {} and {}",
+                    i + 1,
+                    calibration.value,
+                    describe(report.line, &calibration.first),
+                    describe(report.line, &calibration.last)
+                ));
+                lines.push(format!("  {}", report.line));
+                lines.push(format!("  {}", underline(report.line, calibration)));
+            }
+            None => {
+                lines.push(format!("line {}: no digit or number word", i + 1));
+                lines.push(format!("  {}", report.line));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = r##"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"##;
+
+    #[test]
+    fn test_given_2() {
+        let reports = report(SAMPLE_INPUT, &WordMap::default());
+
+        let values: Vec<u64> = reports
+            .iter()
+            .filter_map(|report| report.calibration)
+            .map(|calibration| calibration.value)
+            .collect();
+        assert_eq!(values, vec![29, 83, 13, 24, 42, 14, 76]);
+        let eightwo = reports[1].calibration.unwrap();
+        assert_eq!((eightwo.first.start, eightwo.first.end), (0, 5));
+        assert_eq!(eightwo.first.kind, TokenKind::Word);
+        assert_eq!((eightwo.last.start, eightwo.last.end), (7, 12));
+    }
+
+    #[test]
+    fn annotated() {
+        let reports = report("eightwo3\nabc\nfünf", &WordMap::default());
+
+        assert_eq!(
+            render(&reports),
+            r#"line 1: 83 from "eight" (word, columns 1-5) and "3" (digit, column 8)
+  eightwo3
+  ^^^^^  ^
+line 2: no digit or number word
+  abc
+line 3: no digit or number word
+  fünf"#
+        );
+
+        let german = WordMap::for_locale(crate::Locale::German);
+        let reports = report("xfünf", &german);
+        assert_eq!(
+            render(&reports),
+            r#"line 1: 55 from "fünf" (word, columns 2-5) and "fünf" (word, columns 2-5)
+  xfünf
+   ^^^^"#
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! The number words a calibration line can spell its digits with, one `word: value`
+//! per line. Words can stand for more than one digit, like `twenty: 20`
+
+use aoc_common::{parse, parse_lines, InputLocation};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    Missing(char),
+    InvalidNumber(String),
+    EmptyWord,
+    /// Both words would match at the start of `word`, so which one counts is a coin toss
+    Ambiguous {
+        word: String,
+        prefix: String,
+    },
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Missing(expected) => write!(f, "missing {expected:?}"),
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+            ErrorKind::EmptyWord => write!(f, "the word is empty"),
+            ErrorKind::Ambiguous { word, prefix } => {
+                write!(f, "{word:?} starts with the word {prefix:?}")
+            }
+        }
+    }
+}
+
+impl From<parse::Missing> for ErrorKind {
+    fn from(parse::Missing(separator): parse::Missing) -> Self {
+        ErrorKind::Missing(separator)
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// The vocabularies that come built in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Locale {
+    fn vocabulary(self) -> &'static str {
+        match self {
+            Locale::English => include_str!("../vocabularies/en.txt"),
+            Locale::German => include_str!("../vocabularies/de.txt"),
+            Locale::French => include_str!("../vocabularies/fr.txt"),
+            Locale::Spanish => include_str!("../vocabularies/es.txt"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownLocale(pub String);
+
+impl Display for UnknownLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown locale {:?}, expected en, de, fr or es", self.0)
+    }
+}
+
+impl FromStr for Locale {
+    type Err = UnknownLocale;
+
+    /// The two letter language code
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "en" => Ok(Locale::English),
+            "de" => Ok(Locale::German),
+            "fr" => Ok(Locale::French),
+            "es" => Ok(Locale::Spanish),
+            _ => Err(UnknownLocale(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordMap(HashMap<String, u64>);
+
+/// The English words for one to nine
+impl Default for WordMap {
+    fn default() -> Self {
+        Self::for_locale(Locale::English)
+    }
+}
+
+impl FromStr for WordMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = parse_lines(s, |line| {
+            let (word, value) = parse::header(line)?;
+            if word.is_empty() {
+                return Err(ParseError::new(
+                    InputLocation::of(line, line),
+                    ErrorKind::EmptyWord,
+                ));
+            }
+            let value: u64 = parse::number(line, value.trim())?;
+            Ok((word.to_string(), value))
+        })?;
+        let mut map = HashMap::new();
+        for (i, (word, value)) in words.iter().enumerate() {
+            let word = word.as_str();
+            // Checked both ways round, since the longer word can come first
+            let clash = words[..i]
+                .iter()
+                .map(|(other, _)| other.as_str())
+                .find(|other| word.starts_with(other) || other.starts_with(word));
+            if let Some(other) = clash {
+                let (word, prefix) = if word.len() >= other.len() {
+                    (word, other)
+                } else {
+                    (other, word)
+                };
+                let kind = ErrorKind::Ambiguous {
+                    word: word.to_string(),
+                    prefix: prefix.to_string(),
+                };
+                return Err(ParseError::new(InputLocation::new(i, 0), kind));
+            }
+            map.insert(word.to_string(), *value);
+        }
+        Ok(WordMap(map))
+    }
+}
+
+impl WordMap {
+    pub fn for_locale(locale: Locale) -> Self {
+        locale
+            .vocabulary()
+            .parse()
+            .expect("the built in vocabularies should parse")
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.0.iter().map(|(word, &value)| (word.as_str(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn built_in_locales() {
+        for locale in ["en", "de", "fr", "es"] {
+            let words = WordMap::for_locale(locale.parse().unwrap());
+            assert_eq!(words.0.len(), 9);
+        }
+        assert_eq!(WordMap::for_locale(Locale::German).0.get("fünf"), Some(&5));
+        assert_eq!("nl".parse::<Locale>(), Err(UnknownLocale("nl".to_string())));
+    }
+
+    #[test]
+    fn prefixes_are_ambiguous() {
+        let err = "six: 6\nseven: 7\nsixteen: 16"
+            .parse::<WordMap>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 3, column 1: "sixteen" starts with the word "six""#
+        );
+        let err = "seventeen: 17\nseven: 7".parse::<WordMap>().unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 0));
+
+        let err = "ten 10".parse::<WordMap>().unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Missing(':'));
+        let err = "ten: x".parse::<WordMap>().unwrap_err();
+        assert_eq!(err.to_string(), r#"line 1, column 6: "x" isn't a number"#);
+    }
+}
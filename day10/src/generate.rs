@@ -0,0 +1,169 @@
+//! Random fields with a single loop through `S`, and junk pipes everywhere else
+
+use rand::{seq::SliceRandom, Rng};
+
+const JUNK: &[char] = &['|', '-', 'L', 'J', '7', 'F', '.'];
+
+/// A field and what the loop in it should measure, which only the tests check
+struct Generated {
+    rows: Vec<Vec<char>>,
+    /// How many whole squares of tiles the loop goes around
+    #[cfg(test)]
+    area: usize,
+    #[cfg(test)]
+    loop_length: usize,
+}
+
+/// The squares between four tiles that the loop goes around. The loop is their
+/// outline, so they have to stay in one piece without holes or corners that only touch
+struct Region {
+    size: usize,
+    squares: Vec<bool>,
+}
+
+impl Region {
+    fn contains(&self, row: isize, col: isize) -> bool {
+        let size = self.size as isize;
+        (0..size).contains(&row)
+            && (0..size).contains(&col)
+            && self.squares[(row * size + col) as usize]
+    }
+
+    /// Whether adding the square keeps the outline a single loop. The neighbours
+    /// it has in the region have to be one run around it, and a diagonal neighbour
+    /// has to share an edge with one of the others
+    fn can_add(&self, row: isize, col: isize) -> bool {
+        const AROUND: [(isize, isize); 8] = [
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+            (-1, -1),
+        ];
+        let around = AROUND.map(|(dr, dc)| self.contains(row + dr, col + dc));
+        let pinched = (1..8)
+            .step_by(2)
+            .any(|i| around[i] && !around[i - 1] && !around[(i + 1) % 8]);
+        let runs = (0..8)
+            .filter(|&i| around[i] && !around[(i + 7) % 8])
+            .count();
+        !pinched && runs == 1
+    }
+
+    fn grow(rng: &mut impl Rng, size: usize) -> Self {
+        let mut region = Self {
+            size,
+            squares: vec![false; size * size],
+        };
+        region.squares[rng.gen_range(0..size * size)] = true;
+        let target = rng.gen_range(1..=(size * size).div_ceil(2));
+        let mut area = 1;
+        for _ in 0..size * size * 8 {
+            if area == target {
+                break;
+            }
+            let (row, col) = (rng.gen_range(0..size), rng.gen_range(0..size));
+            let (r, c) = (row as isize, col as isize);
+            let touches = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+                .iter()
+                .any(|(dr, dc)| region.contains(r + dr, c + dc));
+            if !region.contains(r, c) && touches && region.can_add(r, c) {
+                region.squares[row * size + col] = true;
+                area += 1;
+            }
+        }
+        region
+    }
+
+    /// The pipe on the outline at a tile, which is a corner of up to four squares
+    fn pipe(&self, row: isize, col: isize) -> Option<char> {
+        let up = self.contains(row - 1, col - 1) != self.contains(row - 1, col);
+        let down = self.contains(row, col - 1) != self.contains(row, col);
+        let left = self.contains(row - 1, col - 1) != self.contains(row, col - 1);
+        let right = self.contains(row - 1, col) != self.contains(row, col);
+        match (up, down, left, right) {
+            (true, true, false, false) => Some('|'),
+            (false, false, true, true) => Some('-'),
+            (true, false, false, true) => Some('L'),
+            (true, false, true, false) => Some('J'),
+            (false, true, true, false) => Some('7'),
+            (false, true, false, true) => Some('F'),
+            _ => None,
+        }
+    }
+}
+
+impl Generated {
+    fn text(&self) -> String {
+        let rows: Vec<String> = self.rows.iter().map(|row| row.iter().collect()).collect();
+        rows.join("\n")
+    }
+}
+
+fn generated(rng: &mut impl Rng, size: usize) -> Generated {
+    let size = size.max(3);
+    let region = Region::grow(rng, size - 1);
+    let mut rows = vec![vec!['.'; size]; size];
+    let mut on_loop = vec![];
+    for (row, tiles) in rows.iter_mut().enumerate() {
+        for (col, tile) in tiles.iter_mut().enumerate() {
+            *tile = match region.pipe(row as isize, col as isize) {
+                Some(pipe) => {
+                    on_loop.push((row, col));
+                    pipe
+                }
+                None => *JUNK.choose(rng).unwrap(),
+            };
+        }
+    }
+    let &(row, col) = on_loop.choose(rng).unwrap();
+    rows[row][col] = 'S';
+    // Junk pointing into `S` could make a second loop through it
+    for (r, c) in [
+        (row.wrapping_sub(1), col),
+        (row + 1, col),
+        (row, col.wrapping_sub(1)),
+        (row, col + 1),
+    ] {
+        if r < size && c < size && region.pipe(r as isize, c as isize).is_none() {
+            rows[r][c] = '.';
+        }
+    }
+    Generated {
+        rows,
+        #[cfg(test)]
+        area: region.squares.iter().filter(|&&square| square).count(),
+        #[cfg(test)]
+        loop_length: on_loop.len(),
+    }
+}
+
+/// A `size` by `size` field, at least 3 by 3
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    generated(rng, size).text()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn generated_loops_measure_up() {
+        let mut rng = StdRng::seed_from_u64(10);
+        for size in (3..20).cycle().take(100) {
+            let field = generated(&mut rng, size);
+            let input = field.text();
+            // Pick's theorem counts the tiles inside from the area and the outline
+            let inside = field.area + 1 - field.loop_length / 2;
+
+            assert_eq!(part1(&input), Ok(field.loop_length as i64 / 2), "{input}");
+            assert_eq!(part2(&input), Ok(inside as i64), "{input}");
+        }
+    }
+}
@@ -1,35 +1,80 @@
-use std::{convert::Infallible, str::FromStr};
-
-pub fn part1(s: &str) -> i64 {
-    s.parse::<Field>()
-        .unwrap()
-        .build_loop()
-        .furthest_distance()
-        .try_into()
-        .unwrap()
+pub mod generate;
+
+use aoc_common::{InputLocation, Solution};
+use aoc_grid::{Direction, Grid, Point, RaggedRow};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownTile(char),
+    MissingStart,
+    /// No pipe leads out of `S` and back in again
+    NoLoop,
+    RaggedRow(RaggedRow),
 }
 
-pub fn part2(s: &str) -> i64 {
-    let field: Field = s.parse().unwrap();
-    // println!("field: {field:?}");
-    let looping_path: Path = field.build_loop();
-    field.calculate_area_inside_loop(&looping_path)
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownTile(tile) => write!(f, "unknown tile {tile:?}"),
+            ErrorKind::MissingStart => write!(f, "there's no starting tile 'S'"),
+            ErrorKind::NoLoop => write!(f, "there's no loop through the starting tile"),
+            ErrorKind::RaggedRow(ragged) => write!(f, "{ragged}"),
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Location {
-    x: i32,
-    y: i32,
+impl From<RaggedRow> for ErrorKind {
+    fn from(value: RaggedRow) -> Self {
+        ErrorKind::RaggedRow(value)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Field;
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Field, ParseError> {
+        s.parse()
+    }
+
+    fn part1(field: &Field, _config: &()) -> i64 {
+        field.build_loop().furthest_distance().try_into().unwrap()
+    }
+
+    fn part2(field: &Field, _config: &()) -> i64 {
+        // println!("field: {field:?}");
+        let looping_path: Path = field.build_loop();
+        field.calculate_area_inside_loop(&looping_path)
+    }
+}
+
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(Day10::part1(&Day10::parse(s)?, &()))
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(Day10::part2(&Day10::parse(s)?, &()))
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Path {
-    history: Vec<Location>,
+    history: Vec<Point>,
     direction: Direction,
 }
 
 impl Path {
-    fn new(location: Location, direction: Direction) -> Self {
+    fn new(location: Point, direction: Direction) -> Self {
         Path {
             history: vec![location],
             direction,
@@ -39,16 +84,16 @@ impl Path {
         (self.history.len() - 1) / 2
     }
     fn is_done(&self, field: &Field) -> bool {
-        self.history.len() > 0
+        !self.history.is_empty()
             && field
-                .get_tile(&self.current_location())
+                .get_tile(self.current_location())
                 .expect("They better have not put me into an invalid location!")
                 == Tile::Starting
     }
-    fn current_location(&self) -> Location {
-        self.history.last().unwrap().clone()
+    fn current_location(&self) -> Point {
+        *self.history.last().unwrap()
     }
-    fn extend_path(self, new_location: Location, new_direction: Direction) -> Path {
+    fn extend_path(self, new_location: Point, new_direction: Direction) -> Path {
         let mut history = self.history;
         history.push(new_location);
         Path {
@@ -56,45 +101,53 @@ impl Path {
             direction: new_direction,
         }
     }
-    fn contains_location(&self, location: &Location) -> bool {
+    fn contains_location(&self, location: &Point) -> bool {
         self.history.iter().any(|l| l == location)
     }
+    /// The starting tile hides its pipe, so look at where the loop enters and leaves it.
+    /// The history of a finished loop starts and ends on the starting tile
+    fn start_connects_south(&self) -> bool {
+        let below = self.history[0].step(Direction::Down);
+        let after_start = self.history[1];
+        let before_start = self.history[self.history.len() - 2];
+        after_start == below || before_start == below
+    }
 }
 
 #[derive(Debug)]
-struct Field(Vec<Vec<Tile>>);
+pub struct Field(Grid<Tile>);
 impl Field {
-    fn build_loop(&self) -> Path {
+    fn find_loop(&self) -> Option<Path> {
         let starting_location = self.starting_location();
-        let starting_paths = vec![
-            Path::new(starting_location.clone(), Direction::East),
-            Path::new(starting_location.clone(), Direction::West),
-            Path::new(starting_location.clone(), Direction::North),
-            Path::new(starting_location.clone(), Direction::South),
-        ];
-        starting_paths
+        Direction::ALL
             .into_iter()
+            .map(|direction| Path::new(starting_location, direction))
             .find_map(|path| self.run_path_to_loop(path))
-            .expect("We should have found a loop!")
+    }
+
+    /// Parsing checks there is one
+    fn build_loop(&self) -> Path {
+        self.find_loop().expect("We should have found a loop!")
     }
 
     fn calculate_area_inside_loop(&self, looping_path: &Path) -> i64 {
         self.0
-            .iter()
+            .rows()
             .enumerate()
             .map(|(y, row)| Field::calculate_area_row(y.try_into().unwrap(), row, looping_path))
             .sum()
     }
 
-    fn calculate_area_row(y: i32, row: &[Tile], looping_path: &Path) -> i64 {
+    fn calculate_area_row(y: i64, row: &[Tile], looping_path: &Path) -> i64 {
         let mut count = 0;
         let mut inside = false;
         for (x, tile) in row.iter().enumerate() {
-            let location = Location {
-                x: x.try_into().unwrap(),
-                y,
+            let location = Point::new(y, x.try_into().unwrap());
+            let is_separator = match tile {
+                Tile::Starting => looping_path.start_connects_south(),
+                _ => tile.is_vertical_separator(),
             };
-            if tile.is_vertical_separator() && looping_path.contains_location(&location) {
+            if is_separator && looping_path.contains_location(&location) {
                 inside = !inside;
             }
             if !looping_path.contains_location(&location) && inside {
@@ -108,106 +161,59 @@ impl Field {
     /// Goes None if this path cannot successfully complete a loop
     fn run_path_to_loop(&self, path: Path) -> Option<Path> {
         let mut next = self.progress_path(path)?;
-        while !next.is_done(&self) {
+        while !next.is_done(self) {
             // println!("Our path is now {next:?}");
             next = self.progress_path(next)?;
         }
         Some(next)
     }
 
-    fn starting_location(&self) -> Location {
-        let starting_location = self
-            .0
-            .iter()
-            .enumerate()
-            .find_map(|(i, row)| {
-                row.iter()
-                    .position(|tile| (*tile == Tile::Starting))
-                    .and_then(|j| Some((i, j)))
-            })
-            .expect("We better find the starting tuple!");
-        Location {
-            x: starting_location.1.try_into().unwrap(),
-            y: starting_location.0.try_into().unwrap(),
-        }
+    fn starting_location(&self) -> Point {
+        self.0
+            .position(|tile| *tile == Tile::Starting)
+            .expect("We better find the starting tuple!")
+            .into()
     }
 
     /// Locations may be invalid, but this will produce a valid tile inside of this field
-    fn get_tile(&self, location: &Location) -> Option<Tile> {
-        let y: usize = location.y.try_into().ok()?;
-        let x: usize = location.x.try_into().ok()?;
-        if (y > self.height() - 1) || (x > self.width() - 1) {
-            // println!("We are getting a null location for (x,y) {x:?}, {y:?} with height: {}, and width: {}", self.height(), self.width());
-            None
-        } else {
-            Some(self.0.get(y)?.get(x)?.clone())
-        }
-    }
-
-    fn width(&self) -> usize {
-        self.0[0].len()
-    }
-
-    fn height(&self) -> usize {
-        self.0.len()
-    }
-
-    fn get_neighbor(&self, location: &Location, direction: &Direction) -> Location {
-        use Direction::*;
-        match direction {
-            North => Location {
-                x: location.x,
-                y: location.y - 1,
-            },
-            South => Location {
-                x: location.x,
-                y: location.y + 1,
-            },
-            West => Location {
-                x: location.x - 1,
-                y: location.y,
-            },
-            East => Location {
-                x: location.x + 1,
-                y: location.y,
-            },
-        }
+    fn get_tile(&self, location: Point) -> Option<Tile> {
+        self.0.at(location).cloned()
     }
 
     fn get_new_direction(
         &self,
         new_tile: &Tile,
-        incoming_direction: &Direction,
+        incoming_direction: Direction,
     ) -> Option<Direction> {
         use Direction::*;
         use Tile::*;
-        let dir = match &incoming_direction {
-            North => match &new_tile {
-                VerticalPipe => Some(incoming_direction.clone()),
-                SouthWestBend => Some(West),
-                SouthEathBend => Some(East),
-                Starting => Some(incoming_direction.clone()),
+        let dir = match incoming_direction {
+            Up => match &new_tile {
+                VerticalPipe => Some(incoming_direction),
+                SouthWestBend => Some(Left),
+                SouthEathBend => Some(Right),
+                Starting => Some(incoming_direction),
                 _ => None,
             },
-            South => match &new_tile {
-                VerticalPipe => Some(incoming_direction.clone()),
-                NorthEastBend => Some(East),
-                NorthWestBend => Some(West),
-                Starting => Some(incoming_direction.clone()),
+            Down => match &new_tile {
+                VerticalPipe => Some(incoming_direction),
+                NorthEastBend => Some(Right),
+                NorthWestBend => Some(Left),
+                Starting => Some(incoming_direction),
                 _ => None,
             },
-            West => match &new_tile {
-                HorizontalPipe => Some(incoming_direction.clone()),
-                NorthEastBend => Some(North),
-                SouthEathBend => Some(South),
-                Starting => Some(incoming_direction.clone()),
+            Left => match &new_tile {
+                HorizontalPipe => Some(incoming_direction),
+                NorthEastBend => Some(Up),
+                SouthEathBend => Some(Down),
+                Starting => Some(incoming_direction),
                 _ => None,
             },
-            East => match &new_tile {
-                HorizontalPipe => Some(incoming_direction.clone()),
-                NorthWestBend => Some(North),
-                SouthWestBend => Some(South),
-                Starting => Some(incoming_direction.clone()),
+            Right => match &new_tile {
+                HorizontalPipe => Some(incoming_direction),
+                NorthWestBend => Some(Up),
+                SouthWestBend => Some(Down),
+                Starting => Some(incoming_direction),
                 _ => None,
             },
         };
@@ -219,25 +225,16 @@ impl Field {
 
     // fn get_tile_in_direction(&self, location: &Location, direction: &Direction) -> Option<Tile> {}
     fn progress_path(&self, path: Path) -> Option<Path> {
-        let neighbor_location = self.get_neighbor(&path.current_location(), &path.direction);
+        let neighbor_location = path.current_location().step(path.direction);
         // println!("Got neighbor: {neighbor_location:?}");
-        let new_tile = self.get_tile(&neighbor_location)?;
+        let new_tile = self.get_tile(neighbor_location)?;
         // println!("Got tile: {new_tile:?}");
-        let new_direction = self.get_new_direction(&new_tile, &path.direction)?;
+        let new_direction = self.get_new_direction(&new_tile, path.direction)?;
         // println!("Got direction: {new_direction:?}");
         Some(path.extend_path(neighbor_location, new_direction))
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-/// That the pipe is "pointed" towards
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Tile {
     VerticalPipe,
@@ -254,7 +251,7 @@ impl Tile {
     fn is_vertical_separator(&self) -> bool {
         match self {
             Tile::VerticalPipe => true,
-            // This is hacked for my particular real puzzle input :D 
+            // Depends on the loop, see `Path::start_connects_south`
             Tile::Starting => false,
             Tile::SouthWestBend => true,
             Tile::SouthEathBend => true,
@@ -266,10 +263,12 @@ impl Tile {
     }
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         use Tile::*;
-        match value {
+        Ok(match value {
             '|' => VerticalPipe,
             '-' => HorizontalPipe,
             'L' => NorthEastBend,
@@ -278,20 +277,30 @@ impl From<char> for Tile {
             'F' => SouthEathBend,
             '.' => Ground,
             'S' => Starting,
-            _ => unreachable!(),
-        }
+            _ => return Err(ErrorKind::UnknownTile(value)),
+        })
     }
 }
 
 impl FromStr for Field {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Field(
-            s.lines()
-                .map(|line| line.chars().map(|c| c.into()).collect())
-                .collect(),
-        ))
+        let tiles = Grid::parse_chars(s, Tile::try_from)?;
+        let Some((row, col)) = tiles.position(|tile| *tile == Tile::Starting) else {
+            return Err(ParseError::new(
+                InputLocation::new(0, 0),
+                ErrorKind::MissingStart,
+            ));
+        };
+        let field = Field(tiles);
+        if field.find_loop().is_none() {
+            return Err(ParseError::new(
+                InputLocation::new(row, col),
+                ErrorKind::NoLoop,
+            ));
+        }
+        Ok(field)
     }
 }
 
@@ -311,16 +320,14 @@ SJ.L7
 |F--J
 LJ..."##;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
-
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(SIMPLE_SQUARE_LOOP), 4)
+        assert_eq!(part1(SIMPLE_SQUARE_LOOP), Ok(4))
     }
 
     #[test]
     fn test_given_1_2() {
-        assert_eq!(part1(COMPLEX_LOOP), 8)
+        assert_eq!(part1(COMPLEX_LOOP), Ok(8))
     }
 
     const FOUR_LOOP: &str = r##"...........
@@ -336,13 +343,13 @@ LJ..."##;
     #[test]
     fn test_width() {
         let field: Field = FOUR_LOOP.parse().unwrap();
-        assert_eq!(field.height(), 9);
-        assert_eq!(field.width(), 11)
+        assert_eq!(field.0.height(), 9);
+        assert_eq!(field.0.width(), 11)
     }
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(FOUR_LOOP), 4)
+        assert_eq!(part2(FOUR_LOOP), Ok(4))
     }
 
     const BIGGER_LOOP: &str = r##"FF7FSF7F7F7F7F7F---7
@@ -358,6 +365,35 @@ L7JLJL-JLJLJL--JLJ.L"##;
 
     #[test]
     fn test_given_2_2() {
-        assert_eq!(part2(BIGGER_LOOP), 10)
+        assert_eq!(part2(BIGGER_LOOP), Ok(10))
+    }
+
+    #[test]
+    fn test_start_connecting_south() {
+        // `S` is a `|` here, so crossing it goes in and out of the loop
+        let input = ".......\n.F---7.\n.S...|.\n.|...|.\n.L---J.";
+        assert_eq!(part2(input), Ok(6));
+    }
+
+    #[test]
+    fn test_start_not_connecting_south() {
+        // `S` is a `-` on the bottom edge, which doesn't cross into the loop
+        let input = ".........\n.F-----7.\n.|.....|.\n.L--S--J.\n.........";
+        assert_eq!(part2(input), Ok(5));
+    }
+
+    #[test]
+    fn test_no_loop() {
+        let err = part1(".....\n.S-7.\n.|...\n.L-J.").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: there's no loop through the starting tile"
+        );
+    }
+
+    #[test]
+    fn test_unknown_tile() {
+        let err = part1(".....\n.S-7.\n.|x|.").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 3: unknown tile 'x'");
     }
 }
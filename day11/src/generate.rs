@@ -0,0 +1,34 @@
+//! Random images, where most rows and columns have a galaxy or two
+
+use rand::Rng;
+
+/// A `size` by `size` image
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_ratio(1, 12) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn generated_images_solve() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for size in 1..40 {
+            let image = input(&mut rng, size);
+
+            assert!(part1(&image).is_ok(), "{image}");
+            assert!(part2(&image, 1_000_000).is_ok(), "{image}");
+        }
+    }
+}
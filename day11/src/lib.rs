@@ -1,4 +1,34 @@
-use std::{convert::Infallible, str::FromStr};
+pub mod generate;
+
+use aoc_common::Solution;
+use aoc_grid::{Grid, RaggedRow};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownTile(char),
+    RaggedRow(RaggedRow),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownTile(c) => write!(f, "unknown tile {c:?}"),
+            ErrorKind::RaggedRow(ragged) => write!(f, "{ragged}"),
+        }
+    }
+}
+
+impl From<RaggedRow> for ErrorKind {
+    fn from(value: RaggedRow) -> Self {
+        ErrorKind::RaggedRow(value)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 #[derive(Clone, Debug, PartialEq, Copy)]
 enum Tile {
@@ -6,69 +36,69 @@ enum Tile {
     Empty,
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Tile {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             '.' => Tile::Empty,
             '#' => Tile::Galaxy,
-            _ => unreachable!(),
-        }
+            _ => return Err(ErrorKind::UnknownTile(value)),
+        })
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct RawImage(Vec<Vec<Tile>>);
+pub struct RawImage(Grid<Tile>);
 
 impl RawImage {
     fn expansion_rows(&self) -> Vec<usize> {
         self.0
-            .iter()
+            .rows()
             .enumerate()
             .filter_map(|(index, row)| row.iter().all(|t| t == &Tile::Empty).then_some(index))
             .collect()
     }
     fn expansion_columns(&self) -> Vec<usize> {
-        (0..self.0[0].len())
-            .filter(|column_index| {
-                self.0
-                    .iter()
-                    .map(|row| row[*column_index])
-                    .all(|t| t == Tile::Empty)
-            })
+        self.0
+            .columns()
+            .enumerate()
+            .filter_map(|(index, mut column)| column.all(|t| t == &Tile::Empty).then_some(index))
             .collect()
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct ProcessedImage(Vec<Vec<Tile>>);
+struct ProcessedImage(Grid<Tile>);
 
 impl From<RawImage> for ProcessedImage {
     fn from(value: RawImage) -> Self {
-        let mut new_guy = value.clone();
-        // Expand Horizontally
         let empty_rows = value.expansion_rows();
-        let empty_rows_shifted = empty_rows.iter().enumerate().map(|(i, row)| i + row);
-        let empty_row = vec![Tile::Empty; new_guy.0.len()];
-        for index in empty_rows_shifted {
-            new_guy.0.insert(index, empty_row.clone())
-        }
-
-        // Expand Vertically
         let empty_columns = value.expansion_columns();
-        let empty_columns_shifted = empty_columns.iter().enumerate().map(|(i, col)| i + col);
-        for column_index in empty_columns_shifted {
-            for row_index in 0..new_guy.0.len() {
-                new_guy.0[row_index].insert(column_index, Tile::Empty);
+        let mut rows: Vec<Vec<Tile>> = vec![];
+        for (row_index, row) in value.0.rows().enumerate() {
+            // Expand Vertically
+            let mut new_row = vec![];
+            for (column_index, tile) in row.iter().enumerate() {
+                new_row.push(*tile);
+                if empty_columns.contains(&column_index) {
+                    new_row.push(Tile::Empty);
+                }
+            }
+            // Expand Horizontally
+            if empty_rows.contains(&row_index) {
+                rows.push(new_row.clone());
             }
+            rows.push(new_row);
         }
 
-        ProcessedImage(new_guy.0)
+        ProcessedImage(Grid::from_rows(rows))
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MegaExpandedImage {
-    raw: Vec<Vec<Tile>>,
+    raw: Grid<Tile>,
     expansion_rows: Vec<i64>,
     expansion_columns: Vec<i64>,
     expansion_factor: i64,
@@ -77,7 +107,7 @@ pub struct MegaExpandedImage {
 fn count_instances_fast(values: &[i64], lower: &i64, upper: &i64) -> i64 {
     values
         .iter()
-        .filter(|&col| col <= &upper && col >= &lower)
+        .filter(|&col| col <= upper && col >= lower)
         .count()
         .try_into()
         .unwrap()
@@ -126,8 +156,7 @@ impl MegaExpandedImage {
                     count_instances_fast(&self.expansion_rows, &up, &down);
                 let total_vertical_distance: i64 = (base_vertical_distance)
                     + (expansion_rows_crossed * (self.expansion_factor - 1));
-                let distance = total_vertical_distance + total_horizontal_distance;
-                distance
+                total_vertical_distance + total_horizontal_distance
             })
             .collect()
     }
@@ -153,29 +182,18 @@ impl MegaExpandedImage {
 }
 
 impl FromStr for RawImage {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.lines()
-                .map(|line| line.chars().map(|c| c.into()).collect())
-                .collect(),
-        ))
+        Ok(Self(Grid::parse_chars(s, Tile::try_from)?))
     }
 }
 
-fn identify_galaxies(value: &Vec<Vec<Tile>>) -> Vec<(i64, i64)> {
+fn identify_galaxies(value: &Grid<Tile>) -> Vec<(i64, i64)> {
     value
-        .iter()
-        .enumerate()
-        .flat_map(|(i, row)| {
-            row.iter()
-                .enumerate()
-                .filter_map(|(j, tile)| {
-                    (tile == &Tile::Galaxy)
-                        .then_some((i.try_into().unwrap(), j.try_into().unwrap()))
-                })
-                .collect::<Vec<_>>()
+        .indexed_iter()
+        .filter_map(|((i, j), tile)| {
+            (tile == &Tile::Galaxy).then_some((i.try_into().unwrap(), j.try_into().unwrap()))
         })
         .collect()
 }
@@ -201,25 +219,62 @@ impl ProcessedImage {
     }
 }
 
-pub fn part1(s: &str) -> i64 {
-    let processed_image: ProcessedImage = s.parse::<RawImage>().unwrap().into();
-    let distances = processed_image.distances_between_galaxies();
-    distances.iter().sum::<i64>() / 2
+pub struct Config {
+    pub expansion_factor: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            expansion_factor: 1_000_000,
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = RawImage;
+    type Config = Config;
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<RawImage, ParseError> {
+        s.parse()
+    }
+
+    fn part1(raw_image: &RawImage, _config: &Config) -> i64 {
+        let processed_image: ProcessedImage = raw_image.clone().into();
+        let distances = processed_image.distances_between_galaxies();
+        distances.iter().sum::<i64>() / 2
+    }
+
+    fn part2(raw_image: &RawImage, config: &Config) -> i64 {
+        let mega_image: MegaExpandedImage =
+            MegaExpandedImage::new(raw_image.clone(), config.expansion_factor);
+        mega_image.distances_between_galaxies().iter().sum::<i64>()
+    }
+}
+
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(Day11::part1(&Day11::parse(s)?, &Config::default()))
 }
 
-pub fn part2(s: &str, expansion_factor: i64) -> i64 {
-    let raw_image: RawImage = s.parse().unwrap();
-    let mega_image: MegaExpandedImage = MegaExpandedImage::new(raw_image, expansion_factor);
-    mega_image.distances_between_galaxies().iter().sum::<i64>()
+pub fn part2(s: &str, expansion_factor: i64) -> Result<i64, ParseError> {
+    Ok(Day11::part2(
+        &Day11::parse(s)?,
+        &Config { expansion_factor },
+    ))
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
     use std::collections::HashSet;
 
     use super::*;
-
-    const REAL_INPUT: &str = include_str!("../input.txt");
+    use aoc_common::real_input;
 
     const SAMPLE_INPUT: &str = r########"...#......
 .......#..
@@ -234,22 +289,28 @@ mod tests {
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(SAMPLE_INPUT), 374)
+        assert_eq!(part1(SAMPLE_INPUT), Ok(374))
     }
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(SAMPLE_INPUT, 10), 1030)
+        assert_eq!(part2(SAMPLE_INPUT, 10), Ok(1030))
     }
 
     #[test]
     fn test_full_1() {
-        assert_eq!(part1(REAL_INPUT), 9795148)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(9795148))
     }
 
     #[test]
     fn test_full_2() {
-        assert_eq!(part2(REAL_INPUT, 1_000_000), 650672493820)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part2(&input, 1_000_000), Ok(650672493820))
     }
 
     #[test]
@@ -288,14 +349,38 @@ mod tests {
         let sample = r####".#.
 .##
 ..."####;
-        let expected_output = ProcessedImage(vec![
+        let expected_output = ProcessedImage(Grid::from_rows(vec![
             vec![Empty, Empty, Galaxy, Empty],
             vec![Empty, Empty, Galaxy, Galaxy],
             vec![Empty, Empty, Empty, Empty],
             vec![Empty, Empty, Empty, Empty],
-        ]);
+        ]));
 
         let processed: ProcessedImage = sample.parse::<RawImage>().unwrap().into();
         assert_eq!(expected_output, processed);
     }
+
+    fn image() -> impl Strategy<Value = String> {
+        (1..15_usize, 1..15_usize).prop_flat_map(|(width, height)| {
+            let row = prop::collection::vec(prop::bool::weighted(0.15), width);
+            prop::collection::vec(row, height).prop_map(|rows| {
+                let rows: Vec<String> = rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|&galaxy| if galaxy { '#' } else { '.' })
+                            .collect()
+                    })
+                    .collect();
+                rows.join("\n")
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn counted_expansion_matches_doubling(image in image()) {
+            prop_assert_eq!(part2(&image, 2), part1(&image));
+        }
+    }
 }
@@ -0,0 +1,56 @@
+//! Random condition records, made by damaging springs and then forgetting some of them,
+//! so every record has at least one arrangement
+
+use rand::Rng;
+
+/// More unknowns than this and checking every arrangement takes too long
+const MAX_UNKNOWNS: usize = 10;
+
+fn record(rng: &mut impl Rng) -> String {
+    let length = rng.gen_range(1..=20);
+    let mut springs: Vec<char> = (0..length)
+        .map(|_| if rng.gen_ratio(2, 5) { '#' } else { '.' })
+        .collect();
+    let damaged = rng.gen_range(0..length);
+    springs[damaged] = '#';
+    let groups: Vec<String> = springs
+        .split(|&spring| spring == '.')
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len().to_string())
+        .collect();
+    let mut unknowns = 0;
+    for spring in springs.iter_mut() {
+        if unknowns < MAX_UNKNOWNS && rng.gen_ratio(2, 5) {
+            *spring = '?';
+            unknowns += 1;
+        }
+    }
+    let springs: String = springs.into_iter().collect();
+    format!("{springs} {}", groups.join(","))
+}
+
+/// `size` records
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| record(rng))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::part1;
+
+    #[test]
+    fn generated_records_have_arrangements() {
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..100 {
+            let record = input(&mut rng, 1);
+
+            assert!(part1(&record).unwrap() > 0, "{record}");
+        }
+    }
+}
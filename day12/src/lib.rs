@@ -1,4 +1,38 @@
-use std::{collections::VecDeque, convert::Infallible, str::FromStr};
+pub mod generate;
+
+use aoc_common::{parse, parse_lines, InputLocation, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownCondition(char),
+    MissingGroups,
+    InvalidNumber(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownCondition(c) => write!(f, "unknown condition {c:?}"),
+            ErrorKind::MissingGroups => write!(f, "missing the damaged groups after the springs"),
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+        }
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Condition {
@@ -7,14 +41,16 @@ pub enum Condition {
     Unknown,
 }
 
-impl From<char> for Condition {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Condition {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             '#' => Condition::Damaged,
             '.' => Condition::Operational,
             '?' => Condition::Unknown,
-            _ => unreachable!(),
-        }
+            _ => return Err(ErrorKind::UnknownCondition(value)),
+        })
     }
 }
 
@@ -82,15 +118,24 @@ pub struct ConditionRecord {
 }
 
 impl FromStr for ConditionRecord {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (history_str, groups_str) = s.split_once(" ").unwrap();
-        let history: History = History(history_str.chars().map(|c| c.into()).collect());
-        let damaged_groups: Vec<u32> = groups_str
-            .split(",")
-            .map(|num| num.parse().unwrap())
-            .collect();
+        let (history_str, groups_str) = s
+            .split_once(" ")
+            .ok_or_else(|| ParseError::new(InputLocation::of(s, s), ErrorKind::MissingGroups))?;
+        let history: History = History(
+            history_str
+                .chars()
+                .enumerate()
+                .map(|(col, c)| {
+                    Condition::try_from(c)
+                        .map_err(|kind| ParseError::new(InputLocation::new(0, col), kind))
+                })
+                .collect::<Result<_, _>>()?,
+        );
+        let damaged_groups: Vec<u32> = parse::separated(groups_str, ',')
+            .map_err(|err: ParseError| err.within(s, groups_str))?;
         Ok(ConditionRecord {
             history,
             damaged_groups,
@@ -136,29 +181,63 @@ impl ConditionRecord {
     }
 }
 
-pub fn part1(s: &str) -> u32 {
-    s.lines()
-        .map(|line| line.parse::<ConditionRecord>().unwrap())
-        .map(|record| record.different_arangements())
-        .sum()
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<ConditionRecord>;
+    type Config = ();
+    type Output = u32;
+
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(s, str::parse)
+    }
+
+    fn part1(records: &Self::Input, _config: &()) -> u32 {
+        #[cfg(feature = "parallel")]
+        let records = records.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let records = records.iter();
+        records.map(|record| record.different_arangements()).sum()
+    }
+
+    fn part2(records: &Self::Input, _config: &()) -> u32 {
+        #[cfg(feature = "parallel")]
+        let records = records.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let records = records.iter();
+        records.map(|record| record.different_arangements()).sum()
+    }
+}
+
+pub fn part1(s: &str) -> Result<u32, ParseError> {
+    Ok(Day12::part1(&Day12::parse(s)?, &()))
 }
 
-pub fn part2(s: &str) -> u32 {
-    s.lines()
-        .map(|line| line.parse::<ConditionRecord>().unwrap())
-        .map(|record| record.different_arangements())
-        .sum()
+pub fn part2(s: &str) -> Result<u32, ParseError> {
+    Ok(Day12::part2(&Day12::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const REAL_INPUT: &str = include_str!("../input.txt");
+    use aoc_common::real_input;
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(REAL_INPUT), 7344)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(7344))
+    }
+
+    #[test]
+    fn test_unknown_condition() {
+        let err = part1("???.### 1,1,3\n.??..?!...?##. 1,1,3").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 6));
+        assert_eq!(err.kind, ErrorKind::UnknownCondition('!'));
     }
 
     #[test]
@@ -1,4 +1,34 @@
-use std::{convert::Infallible, str::FromStr};
+use aoc_common::{parse, Solution};
+use aoc_grid::{Grid, RaggedRow};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownSquare(char),
+    RaggedRow(RaggedRow),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownSquare(c) => write!(f, "unknown square {c:?}"),
+            ErrorKind::RaggedRow(ragged) => write!(f, "{ragged}"),
+        }
+    }
+}
+
+impl From<RaggedRow> for ErrorKind {
+    fn from(value: RaggedRow) -> Self {
+        ErrorKind::RaggedRow(value)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 #[derive(Debug, PartialEq, Clone)]
 enum Square {
@@ -6,28 +36,27 @@ enum Square {
     Rock,
 }
 
-impl From<char> for Square {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Square {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             '.' => Self::Ash,
             '#' => Self::Rock,
-            _ => unreachable!("Can only have a couple chars"),
-        }
+            _ => return Err(ErrorKind::UnknownSquare(value)),
+        })
     }
 }
 
-struct Pattern {
-    squares: Vec<Vec<Square>>,
+pub struct Pattern {
+    squares: Grid<Square>,
 }
 
 impl FromStr for Pattern {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let squares: Vec<Vec<Square>> = s
-            .lines()
-            .map(|line| line.chars().map(|c| c.into()).collect())
-            .collect();
+        let squares = Grid::parse_chars(s, Square::try_from)?;
         Ok(Self { squares })
     }
 }
@@ -46,9 +75,12 @@ impl MirrorLine {
         }
     }
 }
-fn count_difference_between_squares(one: &[Square], two: &[Square]) -> u32 {
-    one.iter()
-        .zip(two.iter())
+fn count_difference_between_squares<'a>(
+    one: impl IntoIterator<Item = &'a Square>,
+    two: impl IntoIterator<Item = &'a Square>,
+) -> u32 {
+    one.into_iter()
+        .zip(two)
         .filter(|(a, b)| a != b)
         .count()
         .try_into()
@@ -57,37 +89,31 @@ fn count_difference_between_squares(one: &[Square], two: &[Square]) -> u32 {
 
 impl Pattern {
     fn vertically_symmetrical_difference(&self, col: usize) -> u32 {
-        let lowest_distance_to_edge = (self.squares[0].len() - (col + 1)).min(col + 1);
+        let lowest_distance_to_edge = (self.squares.width() - (col + 1)).min(col + 1);
         let should_be_symmetrical_pairs = (1..=lowest_distance_to_edge).map(|distance| {
             (
-                self.squares
-                    .iter()
-                    .map(|row| row[col + 1 - distance].clone())
-                    .collect::<Vec<_>>(),
-                self.squares
-                    .iter()
-                    .map(|row| row[col + distance].clone())
-                    .collect::<Vec<_>>(),
+                self.squares.column(col + 1 - distance),
+                self.squares.column(col + distance),
             )
         });
         should_be_symmetrical_pairs
-            .map(|(one, two)| count_difference_between_squares(&one, &two))
+            .map(|(one, two)| count_difference_between_squares(one, two))
             .sum()
     }
     fn horizontally_symmetrical_difference(&self, row: usize) -> u32 {
-        let lowest_distance_to_edge = (self.squares.len() - (row + 1)).min(row + 1);
+        let lowest_distance_to_edge = (self.squares.height() - (row + 1)).min(row + 1);
         let should_be_symmetrical_pairs = (1..=lowest_distance_to_edge).map(|distance| {
             (
-                &self.squares[row + 1 - distance],
-                &self.squares[row + distance],
+                self.squares.row(row + 1 - distance),
+                self.squares.row(row + distance),
             )
         });
         should_be_symmetrical_pairs
-            .map(|(one, two)| count_difference_between_squares(&one, &two))
+            .map(|(one, two)| count_difference_between_squares(one, two))
             .sum()
     }
     fn mirror_line(&self, allowed_differences: u32) -> MirrorLine {
-        let maybe_horizontal_line = (0..self.squares.len() - 1).find_map(|row| {
+        let maybe_horizontal_line = (0..self.squares.height() - 1).find_map(|row| {
             (self.horizontally_symmetrical_difference(row) == allowed_differences)
                 .then_some(MirrorLine::Horizontal((row + 1).try_into().unwrap()))
         });
@@ -95,7 +121,7 @@ impl Pattern {
             return horizontal_line;
         }
 
-        let maybe_vertical_line = (0..self.squares[0].len() - 1).find_map(|col| {
+        let maybe_vertical_line = (0..self.squares.width() - 1).find_map(|col| {
             (self.vertically_symmetrical_difference(col) == allowed_differences)
                 .then_some(MirrorLine::Vertical((col + 1).try_into().unwrap()))
         });
@@ -105,27 +131,56 @@ impl Pattern {
         unreachable!()
     }
 }
-pub fn part1(s: &str) -> u32 {
-    s.split("\n\n")
-        .map(|block| block.parse::<Pattern>().unwrap())
-        .map(|pattern| pattern.mirror_line(0))
-        .map(|line| line.score())
-        .sum()
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Pattern>;
+    type Config = ();
+    type Output = u32;
+
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_sections(s, str::parse)
+    }
+
+    fn part1(patterns: &Self::Input, _config: &()) -> u32 {
+        #[cfg(feature = "parallel")]
+        let patterns = patterns.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let patterns = patterns.iter();
+        patterns
+            .map(|pattern| pattern.mirror_line(0))
+            .map(|line| line.score())
+            .sum()
+    }
+
+    fn part2(patterns: &Self::Input, _config: &()) -> u32 {
+        #[cfg(feature = "parallel")]
+        let patterns = patterns.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let patterns = patterns.iter();
+        patterns
+            .map(|pattern| pattern.mirror_line(1))
+            .map(|line| line.score())
+            .sum()
+    }
+}
+
+pub fn part1(s: &str) -> Result<u32, ParseError> {
+    Ok(Day13::part1(&Day13::parse(s)?, &()))
 }
 
-pub fn part2(s: &str) -> u32 {
-    s.split("\n\n")
-        .map(|block| block.parse::<Pattern>().unwrap())
-        .map(|pattern| pattern.mirror_line(1))
-        .map(|line| line.score())
-        .sum()
+pub fn part2(s: &str) -> Result<u32, ParseError> {
+    Ok(Day13::part2(&Day13::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::InputLocation;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########"#.##..##.
 ..#.##.#.
 ##......#
@@ -144,11 +199,18 @@ mod tests {
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 405)
+        assert_eq!(part1(GIVEN_INPUT), Ok(405))
     }
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 400)
+        assert_eq!(part2(GIVEN_INPUT), Ok(400))
+    }
+
+    #[test]
+    fn test_unknown_square_in_second_pattern() {
+        let err = part1("#.#\n.#.\n\n##.\n.o#").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(4, 1));
+        assert_eq!(err.kind, ErrorKind::UnknownSquare('o'));
     }
 }
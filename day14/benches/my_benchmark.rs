@@ -1,12 +1,28 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-
-use mylib::*;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, SeedableRng};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = include_str!("../input.txt");
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
     c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
     c.bench_function("part 2", |b| b.iter(|| mylib::part2(black_box(input))));
 }
 
-criterion_group!(benches, criterion_benchmark);
+/// How part 1 scales, on random inputs of a few sizes
+fn generated_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generated part 1");
+    for size in [25, 50, 100] {
+        let input = mylib::generate::input(&mut StdRng::seed_from_u64(0), size);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            input.as_str(),
+            |b, input| b.iter(|| mylib::part1(black_box(input))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark, generated_benchmark);
 criterion_main!(benches);
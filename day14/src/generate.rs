@@ -0,0 +1,38 @@
+//! Random platforms of rounded and cube-shaped rocks
+
+use rand::Rng;
+
+/// A `size` by `size` platform
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.gen_range(0..8) {
+                    0 | 1 => 'O',
+                    2 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn generated_platforms_solve() {
+        let mut rng = StdRng::seed_from_u64(14);
+        for size in 1..30 {
+            let platform = input(&mut rng, size);
+
+            assert!(part1(&platform).is_ok(), "{platform}");
+            assert!(part2(&platform).is_ok(), "{platform}");
+        }
+    }
+}
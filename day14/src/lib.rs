@@ -1,5 +1,33 @@
+pub mod generate;
+
+use aoc_common::Solution;
+use aoc_grid::{Direction, Grid, Point, RaggedRow};
 use core::fmt;
-use std::{collections::HashMap, convert::Infallible, fmt::Display, str::FromStr, time::Instant};
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Instant};
+use tracing::debug;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownTile(char),
+    RaggedRow(RaggedRow),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownTile(c) => write!(f, "unknown tile {c:?}"),
+            ErrorKind::RaggedRow(ragged) => write!(f, "{ragged}"),
+        }
+    }
+}
+
+impl From<RaggedRow> for ErrorKind {
+    fn from(value: RaggedRow) -> Self {
+        ErrorKind::RaggedRow(value)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 #[derive(Debug, PartialEq, Clone, Hash, Eq)]
 pub enum Tile {
@@ -19,166 +47,81 @@ impl Display for Tile {
     }
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Tile {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             'O' => Tile::RoundedRock,
             '#' => Tile::CubeRock,
             '.' => Tile::Empty,
-            _ => unreachable!("Bad tile {value}"),
-        }
-    }
-}
-
-pub enum Direction {
-    North,
-    West,
-    South,
-    East,
-}
-
-impl Direction {
-    fn next(&self) -> Self {
-        use Direction::*;
-        match self {
-            North => West,
-            West => South,
-            South => East,
-            East => North,
-        }
+            _ => return Err(ErrorKind::UnknownTile(value)),
+        })
     }
 }
 
 #[derive(Debug, PartialEq, Clone, Hash, Eq)]
 pub struct Platform {
-    tiles: Vec<Vec<Tile>>,
+    tiles: Grid<Tile>,
 }
 
 impl FromStr for Platform {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let tiles: Vec<Vec<Tile>> = s
-            .lines()
-            .map(|line| line.chars().map(|c| c.into()).collect())
-            .collect();
+        let tiles = Grid::parse_chars(s, Tile::try_from)?;
         Ok(Self { tiles })
     }
 }
 
 impl Platform {
-    fn location_from_blocker(
-        &self,
-        blocker: &Option<&(usize, usize)>,
-        starting_row: usize,
-        starting_col: usize,
-        direction: &Direction,
-    ) -> (usize, usize) {
-        match blocker {
-            Some((row, col)) => match direction {
-                Direction::North => (row + 1, *col),
-                Direction::West => (*row, col + 1),
-                Direction::South => (row - 1, *col),
-                Direction::East => (*row, col - 1),
-            },
-            None => match direction {
-                Direction::North => (0, starting_col),
-                Direction::West => (starting_row, 0),
-                Direction::South => (self.height() - 1, starting_col),
-                Direction::East => (starting_row, self.width() - 1),
-            },
+    /// Where a rounded rock rolls to before it hits a rock or the edge
+    fn find_final_location(&self, start: Point, direction: Direction) -> Point {
+        let mut location = start;
+        loop {
+            let next = location.step(direction);
+            match self.tiles.at(next) {
+                Some(Tile::Empty) => location = next,
+                _ => return location,
+            }
         }
     }
-    fn find_final_location(
-        &self,
-        starting_row: usize,
-        starting_col: usize,
-        direction: &Direction,
-    ) -> (usize, usize) {
-        let potential_blockers =
-            self.build_coordinates_in_direction(starting_row, starting_col, direction);
-        let optional_blocker = potential_blockers
-            .iter()
-            .find(|(row, col)| self.tiles[*row][*col] != Tile::Empty);
-
-        self.location_from_blocker(&optional_blocker, starting_row, starting_col, direction)
-    }
     fn width(&self) -> usize {
-        self.tiles[0].len()
+        self.tiles.width()
     }
     fn height(&self) -> usize {
-        self.tiles.len()
-    }
-    fn build_coordinates_in_direction(
-        &self,
-        starting_row: usize,
-        starting_col: usize,
-        direction: &Direction,
-    ) -> Vec<(usize, usize)> {
-        match direction {
-            Direction::North => (0..starting_row)
-                .rev()
-                .map(|row| (row, starting_col))
-                .collect(),
-            Direction::West => (0..starting_col)
-                .rev()
-                .map(|col| (starting_row, col))
-                .collect(),
-            Direction::South => (starting_row + 1..self.height())
-                .map(|row| (row, starting_col))
-                .collect(),
-            Direction::East => (starting_col + 1..self.width())
-                .map(|col| (starting_row, col))
-                .collect(),
-        }
+        self.tiles.height()
     }
-    fn tiles_to_consider(&self, direction: &Direction) -> Vec<(usize, usize, Tile)> {
+    fn tiles_to_consider(&self, direction: Direction) -> Vec<(Point, Tile)> {
         use Direction::*;
+        let tiles = self
+            .tiles
+            .indexed_iter()
+            .map(|(position, tile)| (Point::from(position), tile.clone()));
         match direction {
-            North | West => self
-                .tiles
-                .iter()
-                .enumerate()
-                .flat_map(move |(row, row_vec)| {
-                    row_vec
-                        .iter()
-                        .enumerate()
-                        .map(move |(col, tile)| (row, col, tile.clone()))
-                })
-                .collect(),
-            South | East => self
-                .tiles
-                .iter()
-                .enumerate()
-                .rev()
-                .flat_map(move |(row, row_vec)| {
-                    row_vec
-                        .iter()
-                        .enumerate()
-                        .rev()
-                        .map(move |(col, tile)| (row, col, tile.clone()))
-                })
-                .collect(),
+            Up | Left => tiles.collect(),
+            Down | Right => tiles.rev().collect(),
         }
     }
-    fn tilt(&self, direction: &Direction) -> Self {
+    fn tilt(&self, direction: Direction) -> Self {
         let mut output: Platform = Platform {
-            tiles: vec![vec![Tile::Empty; self.tiles[0].len()]; self.tiles.len()],
+            tiles: Grid::new(self.width(), self.height(), Tile::Empty),
         };
-        for (row, col, tile) in self.tiles_to_consider(direction) {
-            let (adjusted_row, adjusted_col) = if tile == Tile::RoundedRock {
-                Self::find_final_location(&output, row, col, direction)
+        for (location, tile) in self.tiles_to_consider(direction) {
+            let adjusted = if tile == Tile::RoundedRock {
+                output.find_final_location(location, direction)
             } else {
-                (row, col)
+                location
             };
-            output.tiles[adjusted_row][adjusted_col] = tile.clone()
+            let space = output.tiles.at_mut(adjusted).unwrap();
+            *space = tile
         }
         output
     }
     fn calculate_load(&self) -> u32 {
-        let max_score = self.tiles.len();
+        let max_score = self.height();
         self.tiles
-            .iter()
+            .rows()
             .enumerate()
             .map(|(i, row)| {
                 let rounded_count = row
@@ -191,15 +134,11 @@ impl Platform {
             .try_into()
             .unwrap()
     }
-    fn print(self) -> Self {
-        println!("{self}");
-        self
-    }
     fn cycle(self) -> Self {
-        self.tilt(&Direction::North)
-            .tilt(&Direction::West)
-            .tilt(&Direction::South)
-            .tilt(&Direction::East)
+        self.tilt(Direction::Up)
+            .tilt(Direction::Left)
+            .tilt(Direction::Down)
+            .tilt(Direction::Right)
     }
     fn identify_cycle_length(self) -> IdentifiedCycle {
         let mut seen_platforms: HashMap<Platform, usize> = HashMap::new();
@@ -228,44 +167,56 @@ struct IdentifiedCycle {
 
 impl fmt::Display for Platform {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let lines: Vec<String> = self
-            .tiles
-            .iter()
-            .map(|row| row.iter().map(|tile| tile.to_string()).collect())
-            .collect();
-        let grid = lines.join("\n");
-        write!(f, "Platform: \n{}", grid)
+        write!(f, "Platform: \n{}", self.tiles)
     }
 }
 
-pub fn part1(s: &str) -> u32 {
-    s.parse::<Platform>()
-        .unwrap()
-        .tilt(&Direction::North)
-        .calculate_load()
-}
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Platform;
+    type Config = ();
+    type Output = u32;
+    type Error = ParseError;
 
-pub fn part2(s: &str) -> u32 {
-    let start = Instant::now();
-    let mut platform: Platform = s.parse().unwrap();
-    let identified = platform.clone().identify_cycle_length();
-    platform = identified.platform;
-    println!("Ifentified cycles after {:?}", start.elapsed());
-    let remaining_cycles = (1_000_000_000 - identified.count_reached) % identified.cycle_length;
-    let cycles_to_reach_max = remaining_cycles;
-    for _ in 0..cycles_to_reach_max {
-        platform = platform.cycle();
+    fn parse(s: &str) -> Result<Platform, ParseError> {
+        s.parse()
+    }
+
+    fn part1(platform: &Platform, _config: &()) -> u32 {
+        platform.tilt(Direction::Up).calculate_load()
+    }
+
+    fn part2(platform: &Platform, _config: &()) -> u32 {
+        let start = Instant::now();
+        let identified = platform.clone().identify_cycle_length();
+        let mut platform = identified.platform;
+        debug!("identified cycles after {:?}", start.elapsed());
+        let remaining_cycles = (1_000_000_000 - identified.count_reached) % identified.cycle_length;
+        let cycles_to_reach_max = remaining_cycles;
+        for _ in 0..cycles_to_reach_max {
+            platform = platform.cycle();
+        }
+        let load = platform.calculate_load();
+        debug!("calculated load after {:?}", start.elapsed());
+        load
     }
-    let load = platform.calculate_load();
-    println!("calculated load after {:?}", start.elapsed());
-    load
+}
+
+pub fn part1(s: &str) -> Result<u32, ParseError> {
+    Ok(Day14::part1(&Day14::parse(s)?, &()))
+}
+
+pub fn part2(s: &str) -> Result<u32, ParseError> {
+    Ok(Day14::part2(&Day14::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::real_input;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########"O....#....
 O.OO#....#
 .....##...
@@ -301,7 +252,7 @@ O..#.OO...
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 136)
+        assert_eq!(part1(GIVEN_INPUT), Ok(136))
     }
 
     #[test]
@@ -309,7 +260,7 @@ O..#.OO...
         let input: Platform = GIVEN_INPUT.parse().unwrap();
         let expected: Platform = TILTED_INPUT.parse().unwrap();
 
-        let actual = input.tilt(&Direction::North);
+        let actual = input.tilt(Direction::Up);
 
         assert_eq!(
             expected, actual,
@@ -332,16 +283,22 @@ O..#.OO...
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 64)
+        assert_eq!(part2(GIVEN_INPUT), Ok(64))
     }
 
     #[test]
     fn test_actual_1() {
-        assert_eq!(part1(REAL_INPUT), 109665)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(109665))
     }
 
     #[test]
     fn test_actual_2() {
-        assert_eq!(part2(REAL_INPUT), 96061)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part2(&input), Ok(96061))
     }
 }
@@ -1,4 +1,27 @@
-use std::{convert::Infallible, str::FromStr};
+use aoc_common::{InputLocation, Solution};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingOperation(String),
+    InvalidFocalLength(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingOperation(step) => write!(f, "step {step:?} has no '-' or '='"),
+            ErrorKind::InvalidFocalLength(length) => {
+                write!(f, "focal length {length:?} isn't a number")
+            }
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 pub struct HashString {
     data: String,
@@ -17,7 +40,7 @@ impl HashString {
 
 fn hash(s: &str) -> u32 {
     s.chars().fold(0, |acc, item| {
-        let ascii_value: u32 = (item as u8).try_into().unwrap();
+        let ascii_value: u32 = (item as u8).into();
         ((acc + ascii_value) * 17) % 256
     })
 }
@@ -33,21 +56,30 @@ pub struct Step {
 }
 
 impl FromStr for Step {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.contains('-') {
-            let (label, _) = s.split_once('-').unwrap();
+        if let Some((label, _)) = s.split_once('-') {
             Ok(Self {
                 label: label.to_owned(),
                 operation: Operation::Remove,
             })
-        } else {
-            let (label, focal_length) = s.split_once("=").unwrap();
+        } else if let Some((label, focal_length)) = s.split_once("=") {
+            let focal_length = focal_length.parse().map_err(|_| {
+                ParseError::new(
+                    InputLocation::of(s, focal_length),
+                    ErrorKind::InvalidFocalLength(focal_length.to_string()),
+                )
+            })?;
             Ok(Self {
                 label: label.to_owned(),
-                operation: Operation::Insert(focal_length.parse().unwrap()),
+                operation: Operation::Insert(focal_length),
             })
+        } else {
+            Err(ParseError::new(
+                InputLocation::of(s, s),
+                ErrorKind::MissingOperation(s.to_string()),
+            ))
         }
     }
 }
@@ -131,39 +163,70 @@ impl Boxes {
     }
 }
 
-pub fn part1(s: &str) -> u32 {
-    s.trim()
-        .split(",")
-        .map(|str| HashString::new(str))
-        .map(|s| s.hash())
-        .sum()
+/// Part 1 hashes each step's raw text, part 2 runs the parsed steps
+pub struct InitializationSequence {
+    raw_steps: Vec<HashString>,
+    steps: Vec<Step>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = InitializationSequence;
+    type Config = ();
+    type Output = u32;
+
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<InitializationSequence, ParseError> {
+        let raw_steps: Vec<HashString> = s.trim().split(",").map(HashString::new).collect();
+        let steps: Vec<Step> = s
+            .trim()
+            .split(",")
+            .map(|step| step.parse::<Step>().map_err(|err| err.within(s, step)))
+            .collect::<Result<_, _>>()?;
+        Ok(InitializationSequence { raw_steps, steps })
+    }
+
+    fn part1(sequence: &InitializationSequence, _config: &()) -> u32 {
+        sequence.raw_steps.iter().map(|s| s.hash()).sum()
+    }
+
+    fn part2(sequence: &InitializationSequence, _config: &()) -> u32 {
+        let boxes = Boxes::process_steps(&sequence.steps);
+        boxes.calculate_power()
+    }
+}
+
+pub fn part1(s: &str) -> Result<u32, ParseError> {
+    Ok(Day15::part1(&Day15::parse(s)?, &()))
 }
 
-pub fn part2(s: &str) -> u32 {
-    let steps: Vec<Step> = s
-        .trim()
-        .split(",")
-        .map(|s| s.parse::<Step>().unwrap())
-        .collect();
-    let boxes = Boxes::process_steps(&steps);
-    boxes.calculate_power()
+pub fn part2(s: &str) -> Result<u32, ParseError> {
+    Ok(Day15::part2(&Day15::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str =
         r#########"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"#########;
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 1320)
+        assert_eq!(part1(GIVEN_INPUT), Ok(1320))
     }
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 145)
+        assert_eq!(part2(GIVEN_INPUT), Ok(145))
+    }
+
+    #[test]
+    fn test_bad_focal_length() {
+        let err = part2("rn=1,cm-,qp=x").unwrap_err();
+        assert_eq!(err.to_string(), r#"line 1, column 13: focal length "x" isn't a number"#);
     }
 }
@@ -0,0 +1,42 @@
+//! Random contraptions, mostly empty space between the mirrors and splitters
+
+use rand::{seq::SliceRandom, Rng};
+
+const OPTICS: &[char] = &['/', '\\', '|', '-'];
+
+/// A `size` by `size` contraption
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.gen_ratio(1, 5) {
+                        *OPTICS.choose(rng).unwrap()
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn generated_contraptions_solve() {
+        let mut rng = StdRng::seed_from_u64(16);
+        for size in 1..30 {
+            let contraption = input(&mut rng, size);
+
+            assert!(part1(&contraption).is_ok(), "{contraption}");
+            assert!(part2(&contraption).is_ok(), "{contraption}");
+        }
+    }
+}
@@ -1,4 +1,37 @@
-use std::{collections::HashSet, convert::Infallible, str::FromStr};
+pub mod generate;
+
+use aoc_common::Solution;
+use aoc_grid::{Direction, Grid, Point, RaggedRow};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownTile(char),
+    RaggedRow(RaggedRow),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownTile(c) => write!(f, "unknown tile {c:?}"),
+            ErrorKind::RaggedRow(ragged) => write!(f, "{ragged}"),
+        }
+    }
+}
+
+impl From<RaggedRow> for ErrorKind {
+    fn from(value: RaggedRow) -> Self {
+        ErrorKind::RaggedRow(value)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 #[derive(Clone, Debug, Copy)]
 pub enum MirrorEnum {
@@ -19,82 +52,55 @@ pub enum Tile {
     Splitter(SplitterEnum),
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         use MirrorEnum::*;
         use SplitterEnum::*;
-        match value {
+        Ok(match value {
             '.' => Tile::Empty,
             '-' => Tile::Splitter(Horizontal),
             '|' => Tile::Splitter(Vertical),
             '\\' => Tile::Mirror(DownToTheRight),
             '/' => Tile::Mirror(UpToTheRight),
-            _ => unreachable!(),
-        }
-    }
-}
-
-#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
-pub struct Position {
-    col: i32,
-    row: i32,
-}
-
-impl Position {
-    fn new(row: i32, col: i32) -> Position {
-        Self { row, col }
+            _ => return Err(ErrorKind::UnknownTile(value)),
+        })
     }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Beam {
-    position: Position,
+    position: Point,
     direction: Direction,
 }
 
 impl Beam {
-    fn new(position: Position, direction: Direction) -> Self {
+    fn new(position: Point, direction: Direction) -> Self {
         Beam {
             position,
             direction,
         }
     }
-    fn next_position(&self) -> Position {
-        let row = self.position.row;
-        let col = self.position.col;
-        match self.direction {
-            Direction::Up => Position::new(row - 1, col),
-            Direction::Down => Position::new(row + 1, col),
-            Direction::Left => Position::new(row, col - 1),
-            Direction::Right => Position::new(row, col + 1),
-        }
+    fn next_position(&self) -> Point {
+        self.position.step(self.direction)
     }
 }
 
-pub struct Field(Vec<Vec<Tile>>);
+pub struct Field(Grid<Tile>);
 impl Field {
-    fn width(&self) -> i32 {
-        self.0.len().try_into().unwrap()
+    fn width(&self) -> i64 {
+        self.0.width().try_into().unwrap()
     }
-    fn height(&self) -> i32 {
-        self.0[0].len().try_into().unwrap()
+    fn height(&self) -> i64 {
+        self.0.height().try_into().unwrap()
     }
-    fn get_tile(&self, position: &Position) -> Option<&Tile> {
-        let row_index: Option<usize> = position.row.try_into().ok();
-        let col_index: Option<usize> = position.col.try_into().ok();
-        self.0.get(row_index?)?.get(col_index?)
+    fn get_tile(&self, position: Point) -> Option<&Tile> {
+        self.0.at(position)
     }
     fn progress_beam(&self, beam: &Beam) -> Option<BeamProgress> {
         let beam_next_position = beam.next_position();
-        let tile = self.get_tile(&beam_next_position)?;
+        let tile = self.get_tile(beam_next_position)?;
         use Direction::*;
         use MirrorEnum::*;
         use SplitterEnum::*;
@@ -118,7 +124,7 @@ impl Field {
 
         let new_beams: Vec<Beam> = new_beam_directions
             .into_iter()
-            .map(|direction| Beam::new(beam_next_position.clone(), direction))
+            .map(|direction| Beam::new(beam_next_position, direction))
             .collect();
 
         Some(BeamProgress {
@@ -127,26 +133,23 @@ impl Field {
         })
     }
     fn energized_squares_from(&self, initial_beam: Beam) -> u32 {
-        let width: usize = self.width().try_into().unwrap();
-        let height: usize = self.height().try_into().unwrap();
+        let width = self.0.width();
+        let height = self.0.height();
         let mut beams: Vec<Beam> = vec![initial_beam];
         let mut previously_seen_beams: HashSet<Beam> = HashSet::with_capacity(width * height * 4);
-        let mut energized: HashSet<Position> = HashSet::with_capacity(width * height);
+        let mut energized: HashSet<Point> = HashSet::with_capacity(width * height);
         while let Some(beam) = beams.pop() {
             let progress_opt = self.progress_beam(&beam);
-            match progress_opt {
-                Some(progress) => {
-                    for new_beam in progress.new_beams {
-                        if !previously_seen_beams.contains(&new_beam) {
-                            previously_seen_beams.insert(new_beam.clone());
-                            beams.push(new_beam);
-                        }
-                    }
-                    for energy in progress.energized {
-                        energized.insert(energy);
+            if let Some(progress) = progress_opt {
+                for new_beam in progress.new_beams {
+                    if !previously_seen_beams.contains(&new_beam) {
+                        previously_seen_beams.insert(new_beam.clone());
+                        beams.push(new_beam);
                     }
                 }
-                None => (),
+                for energy in progress.energized {
+                    energized.insert(energy);
+                }
             }
         }
         energized.len().try_into().unwrap()
@@ -155,50 +158,72 @@ impl Field {
 
 pub struct BeamProgress {
     new_beams: Vec<Beam>,
-    energized: HashSet<Position>,
+    energized: HashSet<Point>,
 }
 
 impl FromStr for Field {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let data: Vec<Vec<Tile>> = s
-            .lines()
-            .map(|line| line.chars().map(|c| c.into()).collect())
+        Ok(Self(Grid::parse_chars(s, Tile::try_from)?))
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Field;
+    type Config = ();
+    type Output = u32;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Field, ParseError> {
+        s.parse()
+    }
+
+    fn part1(field: &Field, _config: &()) -> u32 {
+        field.energized_squares_from(Beam::new(Point::new(0, -1), Direction::Right))
+    }
+
+    fn part2(field: &Field, _config: &()) -> u32 {
+        let left_wall =
+            (0..field.height()).map(|row| Beam::new(Point::new(row, -1), Direction::Right));
+        let right_wall = (0..field.height())
+            .map(|row| Beam::new(Point::new(row, field.width()), Direction::Left));
+        let top_wall =
+            (0..field.width()).map(|col| Beam::new(Point::new(-1, col), Direction::Down));
+        let bottom_wall =
+            (0..field.width()).map(|col| Beam::new(Point::new(field.height(), col), Direction::Up));
+        let all_potential_starting_beams: Vec<Beam> = left_wall
+            .chain(right_wall)
+            .chain(top_wall)
+            .chain(bottom_wall)
             .collect();
-        Ok(Self(data))
+        #[cfg(feature = "parallel")]
+        let all_potential_starting_beams = all_potential_starting_beams.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let all_potential_starting_beams = all_potential_starting_beams.into_iter();
+        all_potential_starting_beams
+            .map(|starting_beam| field.energized_squares_from(starting_beam))
+            .max()
+            .unwrap()
     }
 }
 
-pub fn part1(s: &str) -> u32 {
-    let field: Field = s.parse().unwrap();
-    field.energized_squares_from(Beam::new(Position::new(0, -1), Direction::Right))
+pub fn part1(s: &str) -> Result<u32, ParseError> {
+    Ok(Day16::part1(&Day16::parse(s)?, &()))
 }
 
-pub fn part2(s: &str) -> u32 {
-    let field: Field = s.parse().unwrap();
-    let left_wall =
-        (0..field.height()).map(|row| Beam::new(Position::new(row, -1), Direction::Right));
-    let right_wall = (0..field.height())
-        .map(|row| Beam::new(Position::new(row, field.width()), Direction::Left));
-    let top_wall = (0..field.width()).map(|col| Beam::new(Position::new(-1, col), Direction::Down));
-    let bottom_wall =
-        (0..field.width()).map(|col| Beam::new(Position::new(field.height(), col), Direction::Up));
-    let all_potential_starting_beams = left_wall
-        .chain(right_wall)
-        .chain(top_wall)
-        .chain(bottom_wall);
-    all_potential_starting_beams
-        .map(|starting_beam| field.energized_squares_from(starting_beam))
-        .max()
-        .unwrap()
+pub fn part2(s: &str) -> Result<u32, ParseError> {
+    Ok(Day16::part2(&Day16::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::real_input;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########".|...\....
 |.-.\.....
 .....|-...
@@ -212,22 +237,34 @@ mod tests {
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 46)
+        assert_eq!(part1(GIVEN_INPUT), Ok(46))
     }
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 51)
+        assert_eq!(part2(GIVEN_INPUT), Ok(51))
+    }
+
+    #[test]
+    fn test_wide_field_2() {
+        // Only the beam coming in from the right goes through all of it
+        assert_eq!(part2("|.."), Ok(3))
     }
 
     #[test]
     fn test_real_1() {
-        assert_eq!(part1(REAL_INPUT), 8539)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(8539))
     }
 
     #[test]
     #[ignore]
     fn test_real_2() {
-        assert_eq!(part2(REAL_INPUT), 8674)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part2(&input), Ok(8674))
     }
 }
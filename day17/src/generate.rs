@@ -0,0 +1,33 @@
+//! Random maps of how much heat each city block loses
+
+use rand::Rng;
+
+/// A `size` by `size` map
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.gen_range(1..=9)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::part1;
+
+    #[test]
+    fn generated_maps_solve() {
+        let mut rng = StdRng::seed_from_u64(17);
+        for size in 1..15 {
+            let map = input(&mut rng, size);
+
+            assert!(part1(&map).is_ok(), "{map}");
+        }
+    }
+}
@@ -1,56 +1,47 @@
+pub mod generate;
+
+use aoc_common::Solution;
+use aoc_grid::{Direction, Grid, Point, RaggedRow};
 use std::{
     cmp::Ordering,
     collections::{BinaryHeap, HashSet},
-    convert::Infallible,
+    fmt::{self, Display},
     str::FromStr,
 };
+use tracing::debug;
 
-#[derive(PartialEq, Eq, Debug, Clone)]
-pub struct CityMap {
-    blocks: Vec<Vec<u32>>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    NotADigit(char),
+    RaggedRow(RaggedRow),
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Hash)]
-pub struct Location {
-    row: i32,
-    col: i32,
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NotADigit(c) => write!(f, "heat loss {c:?} isn't a digit"),
+            ErrorKind::RaggedRow(ragged) => write!(f, "{ragged}"),
+        }
+    }
 }
 
-impl Location {
-    fn move_direction(&self, direction: &Direction) -> Self {
-        match direction {
-            Up => Self {
-                row: self.row - 1,
-                col: self.col,
-            },
-            Down => Self {
-                row: self.row + 1,
-                col: self.col,
-            },
-            Left => Self {
-                row: self.row,
-                col: self.col - 1,
-            },
-            Right => Self {
-                row: self.row,
-                col: self.col + 1,
-            },
-        }
+impl From<RaggedRow> for ErrorKind {
+    fn from(value: RaggedRow) -> Self {
+        ErrorKind::RaggedRow(value)
     }
 }
 
-#[derive(PartialEq, Eq, Debug, Clone, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CityMap {
+    blocks: Grid<u32>,
 }
 
 #[derive(PartialEq, Eq, Debug, Clone, Hash)]
 pub struct Path {
     total_heat_loss: u32,
-    current_location: Location,
+    current_location: Point,
     direction: Direction,
     moves_in_this_direction: u8,
 }
@@ -66,8 +57,14 @@ impl Path {
 }
 
 impl PartialOrd for Path {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.complex_score().partial_cmp(&other.complex_score())
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Path {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.complex_score().cmp(&other.complex_score())
         // (self.distance_from_start()
         //     .partial_cmp(&other.distance_from_start()))
         // if self.total_heat_loss < other.total_heat_loss {
@@ -79,21 +76,13 @@ impl PartialOrd for Path {
         // }
     }
 }
-
-impl Ord for Path {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-use Direction::*;
-
 impl CityMap {
-    fn get_block(&self, location: &Location) -> Option<u32> {
-        let row: usize = location.row.try_into().ok()?;
-        let col: usize = location.col.try_into().ok()?;
-        self.blocks.get(row)?.get(col).copied()
+    fn get_block(&self, location: Point) -> Option<u32> {
+        self.blocks.at(location).copied()
     }
     pub fn generate_new_paths(&self, path: Path) -> Vec<Path> {
+        use Direction::*;
+        // Spelled out rather than turned, the search is much slower in other orders
         let valid_directions: Vec<Direction> = if path.moves_in_this_direction >= 2 {
             // Must turn
             match path.direction {
@@ -112,8 +101,8 @@ impl CityMap {
         valid_directions
             .into_iter()
             .filter_map(|direction| {
-                let new_location = path.current_location.move_direction(&direction);
-                let heat_cost = self.get_block(&new_location)?;
+                let new_location = path.current_location.step(direction);
+                let heat_cost = self.get_block(new_location)?;
                 let moves_in_this_direction = if path.direction == direction {
                     path.moves_in_this_direction + 1
                 } else {
@@ -129,12 +118,12 @@ impl CityMap {
             .collect()
     }
 
-    fn width(&self) -> i32 {
-        self.blocks[0].len().try_into().unwrap()
+    fn width(&self) -> i64 {
+        self.blocks.width().try_into().unwrap()
     }
 
-    fn height(&self) -> i32 {
-        self.blocks.len().try_into().unwrap()
+    fn height(&self) -> i64 {
+        self.blocks.height().try_into().unwrap()
     }
 
     fn path_reached_end(&self, path: &Path) -> bool {
@@ -146,14 +135,14 @@ impl CityMap {
         let mut paths_in_consideration: BinaryHeap<Path> = BinaryHeap::new();
         paths_in_consideration.push(Path {
             total_heat_loss: 0,
-            current_location: Location { row: 0, col: 0 },
-            direction: Down,
+            current_location: Point::new(0, 0),
+            direction: Direction::Down,
             moves_in_this_direction: 0,
         });
         paths_in_consideration.push(Path {
             total_heat_loss: 0,
-            current_location: Location { row: 0, col: 0 },
-            direction: Right,
+            current_location: Point::new(0, 0),
+            direction: Direction::Right,
             moves_in_this_direction: 0,
         });
         let mut winning_path: Option<Path> = None;
@@ -195,37 +184,50 @@ impl CityMap {
 }
 
 impl FromStr for CityMap {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let blocks: Vec<Vec<u32>> = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_string().parse::<u32>().unwrap())
-                    .collect()
-            })
-            .collect();
+        let blocks = Grid::parse_chars(s, |c| c.to_digit(10).ok_or(ErrorKind::NotADigit(c)))?;
         Ok(Self { blocks })
     }
 }
 
-pub fn part1(s: &str) -> u32 {
-    let city_map: CityMap = s.parse().unwrap();
-    let best_path = city_map.find_smallest_heat_loss_path();
-    println!("WE found the best path of {best_path:?}");
-    best_path.total_heat_loss
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = CityMap;
+    type Config = ();
+    type Output = u32;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<CityMap, ParseError> {
+        s.parse()
+    }
+
+    fn part1(city_map: &CityMap, _config: &()) -> u32 {
+        let best_path = city_map.find_smallest_heat_loss_path();
+        debug!("found the best path of {best_path:?}");
+        best_path.total_heat_loss
+    }
+
+    fn part2(_city_map: &CityMap, _config: &()) -> u32 {
+        0
+    }
+}
+
+pub fn part1(s: &str) -> Result<u32, ParseError> {
+    Ok(Day17::part1(&Day17::parse(s)?, &()))
 }
 
-pub fn part2(s: &str) -> u32 {
-    0
+pub fn part2(s: &str) -> Result<u32, ParseError> {
+    Ok(Day17::part2(&Day17::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########"2413432311323
 3215453535623
 3255245654254
@@ -242,12 +244,12 @@ mod tests {
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 102)
+        assert_eq!(part1(GIVEN_INPUT), Ok(102))
     }
 
     #[test]
     #[ignore]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 51)
+        assert_eq!(part2(GIVEN_INPUT), Ok(51))
     }
 }
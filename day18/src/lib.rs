@@ -1,52 +1,45 @@
-use std::{convert::Infallible, str::FromStr};
+use aoc_common::{parse_lines, InputLocation, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+use tracing::{debug, trace};
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct Location {
-    row: i32,
-    col: i32,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A step is a direction, a distance and a color
+    WrongPartCount(usize),
+    UnknownDirection(String),
+    InvalidDistance(String),
 }
 
-impl Location {
-    fn move_step(&self, step: &Step) -> Vec<(Location, ColorCode)> {
-        let locations: Vec<Location> = match step.direction {
-            Direction::Up => (1..=step.distance)
-                .map(|up| Location {
-                    row: self.row - up,
-                    col: self.col,
-                })
-                .collect(),
-            Direction::Down => (1..=step.distance)
-                .map(|down| Location {
-                    row: self.row + down,
-                    col: self.col,
-                })
-                .collect(),
-            Direction::Left => (1..=step.distance)
-                .map(|left| Location {
-                    row: self.row,
-                    col: self.col - left,
-                })
-                .collect(),
-            Direction::Right => (1..=step.distance)
-                .map(|right| Location {
-                    row: self.row,
-                    col: self.col + right,
-                })
-                .collect(),
-        };
-        locations
-            .into_iter()
-            .map(|location| (location, step.color_code.clone()))
-            .collect()
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::WrongPartCount(count) => write!(
+                f,
+                "a step needs a direction, distance and color, but this has {count} parts"
+            ),
+            ErrorKind::UnknownDirection(direction) => write!(f, "unknown direction {direction:?}"),
+            ErrorKind::InvalidDistance(distance) => {
+                write!(f, "distance {distance:?} isn't a number")
+            }
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+fn move_step(location: Point, step: &Step) -> Vec<(Point, ColorCode)> {
+    (1..=step.distance)
+        .map(|distance| {
+            (
+                location.step_by(step.direction, distance),
+                step.color_code.clone(),
+            )
+        })
+        .collect()
 }
 
 type ColorCode = String;
@@ -54,26 +47,39 @@ type ColorCode = String;
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct Step {
     direction: Direction,
-    distance: i32,
+    distance: i64,
     color_code: ColorCode,
 }
 
 impl FromStr for Step {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let substrs: Vec<&str> = s.split(" ").collect();
-        let dir_string = substrs[0];
-        let distance_string = substrs[1];
-        let color_code = substrs[2].to_owned();
-        let direction: Direction = match dir_string {
-            "R" => Direction::Right,
-            "L" => Direction::Left,
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            _ => unreachable!(),
+        let [dir_string, distance_string, color_code] = substrs[..] else {
+            return Err(ParseError::new(
+                InputLocation::of(s, s),
+                ErrorKind::WrongPartCount(substrs.len()),
+            ));
         };
-        let distance: i32 = distance_string.parse().unwrap();
+        let color_code = color_code.to_owned();
+        let mut dir_chars = dir_string.chars();
+        let direction = match (dir_chars.next(), dir_chars.next()) {
+            (Some(letter), None) => Direction::from_udlr(letter),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            ParseError::new(
+                InputLocation::of(s, dir_string),
+                ErrorKind::UnknownDirection(dir_string.to_string()),
+            )
+        })?;
+        let distance: i64 = distance_string.parse().map_err(|_| {
+            ParseError::new(
+                InputLocation::of(s, distance_string),
+                ErrorKind::InvalidDistance(distance_string.to_string()),
+            )
+        })?;
         Ok(Self {
             direction,
             distance,
@@ -83,27 +89,27 @@ impl FromStr for Step {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Steps(Vec<Step>);
+pub struct Steps(Vec<Step>);
 
 impl FromStr for Steps {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.lines().map(|line| line.parse().unwrap()).collect()))
+        Ok(Self(parse_lines(s, str::parse)?))
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Locations(Vec<(Location, ColorCode)>);
+struct Locations(Vec<(Point, ColorCode)>);
 
 impl From<Steps> for Locations {
     fn from(value: Steps) -> Self {
-        let mut locations: Vec<(Location, ColorCode)> = vec![];
-        let mut past_position = Location { row: 0, col: 0 };
+        let mut locations: Vec<(Point, ColorCode)> = vec![];
+        let mut past_position = Point::new(0, 0);
         for step in value.0.into_iter() {
-            let mut next_locations = past_position.move_step(&step);
+            let mut next_locations = move_step(past_position, &step);
             past_position = match next_locations.last() {
-                Some(location) => location.0.clone(),
+                Some(location) => location.0,
                 None => past_position,
             };
             locations.append(&mut next_locations);
@@ -113,51 +119,24 @@ impl From<Steps> for Locations {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Layout(Vec<Vec<Option<ColorCode>>>);
+struct Layout(Grid<Option<ColorCode>>);
 impl From<Locations> for Layout {
     fn from(locations: Locations) -> Self {
-        let lowest_row: usize = locations
-            .0
-            .iter()
-            .map(|location| location.0.row)
-            .min()
-            .unwrap()
-            .try_into()
-            .unwrap();
-        let lowest_col: usize = locations
-            .0
-            .iter()
-            .map(|location| location.0.col)
-            .min()
-            .unwrap()
-            .try_into()
-            .unwrap();
-        let highest_row: usize = locations
-            .0
-            .iter()
-            .map(|location| location.0.row)
-            .max()
-            .unwrap()
-            .try_into()
-            .unwrap();
-        let highest_col: usize = locations
-            .0
-            .iter()
-            .map(|location| location.0.col)
-            .max()
-            .unwrap()
-            .try_into()
-            .unwrap();
-
-        let width: usize = ((highest_col - lowest_col) + 1).try_into().unwrap();
-        let height: usize = ((highest_row - lowest_row) + 1).try_into().unwrap();
-        let mut layout: Vec<Vec<Option<ColorCode>>> = vec![vec![None; width]; height];
-        for location in locations.0 {
-            let row: usize = location.0.row.try_into().unwrap();
-            let col: usize = location.0.col.try_into().unwrap();
-            let row: usize = row - lowest_row;
-            let col: usize = col - lowest_col;
-            layout[row][col] = Some(location.1);
+        let rows = || locations.0.iter().map(|location| location.0.row);
+        let cols = || locations.0.iter().map(|location| location.0.col);
+        let lowest_row = rows().min().unwrap();
+        let lowest_col = cols().min().unwrap();
+        let highest_row = rows().max().unwrap();
+        let highest_col = cols().max().unwrap();
+
+        let width: usize = (highest_col - lowest_col + 1).try_into().unwrap();
+        let height: usize = (highest_row - lowest_row + 1).try_into().unwrap();
+        let mut layout: Grid<Option<ColorCode>> = Grid::new(width, height, None);
+        for (location, color_code) in &locations.0 {
+            let space = layout
+                .get_mut(location.row - lowest_row, location.col - lowest_col)
+                .unwrap();
+            *space = Some(color_code.clone());
         }
         Layout(layout)
     }
@@ -166,63 +145,82 @@ impl From<Locations> for Layout {
 impl Layout {
     fn pretty_string(&self) -> String {
         self.0
-            .iter()
-            .map(|row| {
-                row.iter()
-                    .map(|location| match location {
-                        Some(_) => "#",
-                        None => ".",
-                    })
-                    .collect::<Vec<_>>()
-                    .join("")
+            .map(|location| match location {
+                Some(_) => '#',
+                None => '.',
             })
-            .collect::<Vec<_>>()
-            .join("\n")
+            .to_string()
     }
+    /// Flood fills the outside from the edges, everything it can't reach is inside the trench
     fn fill_inside(&mut self) {
-        for row in self.0.iter_mut() {
-            let mut inside = false;
-            for space in row.iter_mut() {
-                if space.is_some() {
-                    inside = !inside;
-                }
-                if inside {
-                    *space = Some("hello".to_string());
-                }
+        let height = self.0.height();
+        let width = self.0.width();
+        let mut outside = Grid::new(width, height, false);
+        let mut to_visit: Vec<(usize, usize)> = (0..height)
+            .flat_map(|row| [(row, 0), (row, width - 1)])
+            .chain((0..width).flat_map(|col| [(0, col), (height - 1, col)]))
+            .collect();
+        while let Some((row, col)) = to_visit.pop() {
+            if outside[(row, col)] || self.0[(row, col)].is_some() {
+                continue;
+            }
+            outside[(row, col)] = true;
+            to_visit.extend(self.0.neighbors4(row, col));
+        }
+        for (position, is_outside) in outside.indexed_iter() {
+            let space = &mut self.0[position];
+            if space.is_none() && !is_outside {
+                *space = Some("hello".to_string());
             }
         }
     }
     fn count_volume(&self) -> u32 {
-        let sum: usize = self
-            .0
-            .iter()
-            .map(|row| row.iter().filter(|tile| tile.is_some()).count())
-            .sum();
+        let sum: usize = self.0.iter().filter(|tile| tile.is_some()).count();
         sum.try_into().unwrap()
     }
 }
 
-pub fn part1(s: &str) -> u32 {
-    let steps: Steps = s.parse().unwrap();
-    let locations: Locations = steps.into();
-    let mut layout: Layout = locations.into();
-    println!("Layout: {layout:?}");
-    println!("before filling we have: {:?}", layout.count_volume());
-    println!("layout_pretty: {:?}", layout.pretty_string());
-    layout.fill_inside();
-    println!("layout_pretty after filling: {:?}", layout.pretty_string());
-    layout.count_volume()
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Steps;
+    type Config = ();
+    type Output = u32;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Steps, ParseError> {
+        s.parse()
+    }
+
+    fn part1(steps: &Steps, _config: &()) -> u32 {
+        let locations: Locations = steps.clone().into();
+        let mut layout: Layout = locations.into();
+        trace!("layout: {layout:?}");
+        debug!("before filling we have: {:?}", layout.count_volume());
+        trace!("layout_pretty: {:?}", layout.pretty_string());
+        layout.fill_inside();
+        trace!("layout_pretty after filling: {:?}", layout.pretty_string());
+        layout.count_volume()
+    }
+
+    fn part2(_steps: &Steps, _config: &()) -> u32 {
+        0
+    }
+}
+
+pub fn part1(s: &str) -> Result<u32, ParseError> {
+    Ok(Day18::part1(&Day18::parse(s)?, &()))
 }
 
-pub fn part2(s: &str) -> u32 {
-    0
+pub fn part2(s: &str) -> Result<u32, ParseError> {
+    Ok(Day18::part2(&Day18::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########"R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
@@ -240,12 +238,42 @@ U 2 (#7a21e3)"#########;
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 62)
+        assert_eq!(part1(GIVEN_INPUT), Ok(62))
+    }
+
+    #[test]
+    fn test_given_filled() {
+        let steps: Steps = GIVEN_INPUT.parse().unwrap();
+        let mut layout: Layout = Locations::from(steps).into();
+
+        layout.fill_inside();
+
+        // Flipping inside and out at every trench tile goes wrong on rows along the trench
+        assert_eq!(
+            layout.pretty_string(),
+            r#"#######
+#######
+#######
+..#####
+..#####
+#######
+#####..
+#######
+.######
+.######"#
+        );
+    }
+
+    #[test]
+    fn test_unknown_direction() {
+        let err = part1("R 6 (#70c710)\nX 5 (#0dc571)").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 0));
+        assert_eq!(err.kind, ErrorKind::UnknownDirection("X".to_string()));
     }
 
     #[test]
     #[ignore]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 51)
+        assert_eq!(part2(GIVEN_INPUT), Ok(51))
     }
 }
@@ -0,0 +1,84 @@
+//! Random systems of workflows and parts. Workflows only send parts on to workflows
+//! later in the list, so every part ends up accepted or rejected
+
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+const CATEGORIES: &[char] = &['x', 'm', 'a', 's'];
+
+fn names(rng: &mut impl Rng, count: usize) -> Vec<String> {
+    let mut names = vec!["in".to_string()];
+    let mut used: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < count {
+        let length = rng.gen_range(2..=3);
+        let name: String = (0..length)
+            .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+            .collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+fn destination<'a>(rng: &mut impl Rng, later: &'a [String]) -> &'a str {
+    match rng.gen_range(0..later.len() + 2) {
+        0 => "A",
+        1 => "R",
+        i => &later[i - 2],
+    }
+}
+
+fn workflow(rng: &mut impl Rng, name: &str, later: &[String]) -> String {
+    let mut rules: Vec<String> = (0..rng.gen_range(1..=4))
+        .map(|_| {
+            let category = CATEGORIES.choose(rng).unwrap();
+            let comparison = if rng.gen() { '<' } else { '>' };
+            let amount = rng.gen_range(1..=4000);
+            let destination = destination(rng, later);
+            format!("{category}{comparison}{amount}:{destination}")
+        })
+        .collect();
+    rules.push(destination(rng, later).to_string());
+    format!("{name}{{{}}}", rules.join(","))
+}
+
+fn part(rng: &mut impl Rng) -> String {
+    let ratings: Vec<String> = CATEGORIES
+        .iter()
+        .map(|category| format!("{category}={}", rng.gen_range(1..=4000)))
+        .collect();
+    format!("{{{}}}", ratings.join(","))
+}
+
+/// `size` workflows, starting with `in`, and `size` parts
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let names = names(rng, size.max(1));
+    let workflows: Vec<String> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| workflow(rng, name, &names[i + 1..]))
+        .collect();
+    let parts: Vec<String> = (0..size).map(|_| part(rng)).collect();
+    format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn generated_systems_solve() {
+        let mut rng = StdRng::seed_from_u64(19);
+        for size in 1..50 {
+            let system = input(&mut rng, size);
+
+            assert!(part1(&system).is_ok(), "{system}");
+            assert!(part2(&system).is_ok(), "{system}");
+        }
+    }
+}
@@ -1,4 +1,55 @@
-use std::{collections::HashMap, convert::Infallible, str::FromStr};
+pub mod generate;
+
+use aoc_common::{parse, parse_lines, InputLocation, Solution};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingParts,
+    Missing(char),
+    UnknownCategory(String),
+    InvalidNumber(String),
+    /// A part is rated in exactly four categories
+    WrongRatingCount(usize),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingParts => write!(f, "missing the blank line before the parts"),
+            ErrorKind::Missing(expected) => write!(f, "missing {expected:?}"),
+            ErrorKind::UnknownCategory(category) => write!(f, "unknown category {category:?}"),
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+            ErrorKind::WrongRatingCount(count) => {
+                write!(f, "a part needs 4 ratings, but this has {count}")
+            }
+        }
+    }
+}
+
+impl From<parse::Missing> for ErrorKind {
+    fn from(parse::Missing(expected): parse::Missing) -> Self {
+        ErrorKind::Missing(expected)
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+impl From<parse::WrongCount> for ErrorKind {
+    fn from(wrong: parse::WrongCount) -> Self {
+        ErrorKind::WrongRatingCount(wrong.found)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 enum Category {
@@ -9,7 +60,7 @@ enum Category {
 }
 
 impl FromStr for Category {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
@@ -17,7 +68,12 @@ impl FromStr for Category {
             "m" => Self::Musical,
             "a" => Self::Aerodynamic,
             "s" => Self::Shiny,
-            _ => unreachable!(),
+            _ => {
+                return Err(ParseError::new(
+                    InputLocation::of(s, s),
+                    ErrorKind::UnknownCategory(s.to_string()),
+                ))
+            }
         })
     }
 }
@@ -28,12 +84,12 @@ type WorkflowName = String;
 struct Workflows(HashMap<WorkflowName, Workflow>);
 
 impl FromStr for Workflows {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self(
-            s.lines()
-                .map(|line| line.parse::<WorkflowRow>().unwrap())
+            parse_lines(s, str::parse::<WorkflowRow>)?
+                .into_iter()
                 .map(|row| (row.0, row.1))
                 .collect(),
         ))
@@ -65,8 +121,7 @@ impl Workflows {
                 let moved_blocks = self.get_workflow(workflow_name).split_and_move_block(block);
                 moved_blocks
                     .into_iter()
-                    .map(|block| self.run_block_to_ending_states(block))
-                    .flatten()
+                    .flat_map(|block| self.run_block_to_ending_states(block))
                     .collect()
             }
         }
@@ -84,7 +139,7 @@ impl Range {
         Self { lowest, highest }
     }
     pub fn element_count(&self) -> u64 {
-        ((self.highest - self.lowest) + 1).into()
+        (self.highest - self.lowest) + 1
     }
 }
 
@@ -200,7 +255,7 @@ enum Position {
 }
 
 impl FromStr for Position {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
@@ -248,14 +303,18 @@ enum Rule {
 }
 
 impl FromStr for Rule {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.split_once(":") {
             Some((condition, destination)) => {
-                let destination: Position = destination.parse().unwrap();
-                let category: Category = condition[0..1].parse().unwrap();
-                let amount: u64 = condition[2..].parse().unwrap();
+                let destination: Position = destination.parse()?;
+                let category = condition.get(0..1).unwrap_or(condition);
+                let category: Category = category
+                    .parse()
+                    .map_err(|err: ParseError| err.within(s, category))?;
+                let amount = condition.get(2..).unwrap_or(&condition[condition.len()..]);
+                let amount: u64 = parse::number(s, amount)?;
                 let split_direction: SplitDirection = if condition.contains('<') {
                     SplitDirection::LessThan
                 } else {
@@ -268,7 +327,7 @@ impl FromStr for Rule {
                     destination,
                 ))
             }
-            None => Rule::FallThrough(s.parse::<Position>().unwrap()),
+            None => Rule::FallThrough(s.parse::<Position>()?),
         })
     }
 }
@@ -279,10 +338,13 @@ struct Workflow {
 }
 
 impl FromStr for Workflow {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rules: Vec<Rule> = s.split(",").map(|a| a.parse().unwrap()).collect();
+        let rules: Vec<Rule> = s
+            .split(",")
+            .map(|a| a.parse().map_err(|err: ParseError| err.within(s, a)))
+            .collect::<Result<_, _>>()?;
         Ok(Self { rules })
     }
 }
@@ -297,9 +359,8 @@ impl Workflow {
                     None => (output, None),
                     Some(not_moved) => {
                         let rule_result = not_moved.apply_rule(rule);
-                        match rule_result.moved_block {
-                            Some(moved) => output.push(moved),
-                            None => {}
+                        if let Some(moved) = rule_result.moved_block {
+                            output.push(moved);
                         }
                         (output, rule_result.not_moved_block)
                     }
@@ -331,12 +392,14 @@ impl Workflow {
 struct WorkflowRow(WorkflowName, Workflow);
 
 impl FromStr for WorkflowRow {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (name, rest) = s.split_once("{").unwrap();
+        let (name, rest) = parse::split_once(s, '{')?;
         let rest_clean = rest.trim_end_matches("}");
-        let workflow: Workflow = rest_clean.parse().unwrap();
+        let workflow: Workflow = rest_clean
+            .parse()
+            .map_err(|err: ParseError| err.within(s, rest_clean))?;
         Ok(WorkflowRow(name.to_owned(), workflow))
     }
 }
@@ -350,20 +413,21 @@ struct Part {
 }
 
 impl FromStr for Part {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn extract_num(s: &str) -> u64 {
-            let (_, num) = s.split_once("=").unwrap();
-            num.parse().unwrap()
-        }
+        let extract_num = |rating: &str| {
+            let (_, num) =
+                parse::split_once(rating, '=').map_err(|err: ParseError| err.within(s, rating))?;
+            parse::number(s, num)
+        };
 
         let trimmed = s.trim_start_matches("{").trim_end_matches("}");
-        let parts: Vec<&str> = trimmed.split(",").collect();
-        let extremely_cool = extract_num(parts[0]);
-        let musical = extract_num(parts[1]);
-        let aerodynamic = extract_num(parts[2]);
-        let shiny = extract_num(parts[3]);
+        let [extremely_cool, musical, aerodynamic, shiny] = parse::exactly(s, trimmed.split(','))?;
+        let extremely_cool = extract_num(extremely_cool)?;
+        let musical = extract_num(musical)?;
+        let aerodynamic = extract_num(aerodynamic)?;
+        let shiny = extract_num(shiny)?;
         Ok(Self {
             extremely_cool,
             musical,
@@ -393,53 +457,78 @@ enum EndingState {
     Rejected,
 }
 
-pub fn part1(s: &str) -> u64 {
-    let (workflow_section, part_section) = s.split_once("\n\n").unwrap();
-    let workflows: Workflows = workflow_section.parse().unwrap();
-    let parts: Vec<Part> = part_section
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .collect();
+pub struct System {
+    workflows: Workflows,
+    parts: Vec<Part>,
+}
 
-    parts
-        .iter()
-        .filter_map(|part| match workflows.find_end_state(part) {
-            EndingState::Accepted => Some(part.score()),
-            EndingState::Rejected => None,
-        })
-        .sum()
-}
-
-pub fn part2(s: &str) -> u64 {
-    let (workflow_section, _part_section) = s.split_once("\n\n").unwrap();
-    let workflows: Workflows = workflow_section.parse().unwrap();
-    let block = RatingBlock {
-        position: Position::NextWorkflow("in".to_string()),
-        extremely_cool: Range {
-            lowest: 1,
-            highest: 4000,
-        },
-        musical: Range {
-            lowest: 1,
-            highest: 4000,
-        },
-        aerodynamic: Range {
-            lowest: 1,
-            highest: 4000,
-        },
-        shiny: Range {
-            lowest: 1,
-            highest: 4000,
-        },
-    };
-
-    workflows
-        .run_block_to_ending_states(block)
-        .into_iter()
-        // .inspect(|block| println!("ending block: {:?}", block))
-        .filter(|block| block.position == Position::EndingState(EndingState::Accepted))
-        .map(|block| block.parts_in_block())
-        .sum()
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = System;
+    type Config = ();
+    type Output = u64;
+
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<System, ParseError> {
+        let (workflow_section, part_section) = parse::two_sections(s, ErrorKind::MissingParts)?;
+        let workflows: Workflows = workflow_section.parse()?;
+        let parts: Vec<Part> =
+            parse_lines(part_section, str::parse).map_err(|err| err.within(s, part_section))?;
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(system: &System, _config: &()) -> u64 {
+        system
+            .parts
+            .iter()
+            .filter_map(|part| match system.workflows.find_end_state(part) {
+                EndingState::Accepted => Some(part.score()),
+                EndingState::Rejected => None,
+            })
+            .sum()
+    }
+
+    fn part2(system: &System, _config: &()) -> u64 {
+        let block = RatingBlock {
+            position: Position::NextWorkflow("in".to_string()),
+            extremely_cool: Range {
+                lowest: 1,
+                highest: 4000,
+            },
+            musical: Range {
+                lowest: 1,
+                highest: 4000,
+            },
+            aerodynamic: Range {
+                lowest: 1,
+                highest: 4000,
+            },
+            shiny: Range {
+                lowest: 1,
+                highest: 4000,
+            },
+        };
+
+        system
+            .workflows
+            .run_block_to_ending_states(block)
+            .into_iter()
+            // .inspect(|block| println!("ending block: {:?}", block))
+            .filter(|block| block.position == Position::EndingState(EndingState::Accepted))
+            .map(|block| block.parts_in_block())
+            .sum()
+    }
+}
+
+pub fn part1(s: &str) -> Result<u64, ParseError> {
+    Ok(Day19::part1(&Day19::parse(s)?, &()))
+}
+
+pub fn part2(s: &str) -> Result<u64, ParseError> {
+    Ok(Day19::part2(&Day19::parse(s)?, &()))
 }
 
 #[cfg(test)]
@@ -447,8 +536,8 @@ mod tests {
     use std::collections::HashSet;
 
     use super::*;
+    use aoc_common::real_input;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########"px{a<2006:qkq,m>2090:A,rfg}
 pv{a>1716:R,A}
 lnx{m>1548:A,A}
@@ -469,7 +558,28 @@ hdj{m>838:A,pv}
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 19114)
+        assert_eq!(part1(GIVEN_INPUT), Ok(19114))
+    }
+
+    #[test]
+    fn test_unknown_category() {
+        let err =
+            part1("in{s<1351:px,qqz}\npx{q>2662:A,R}\n\n{x=787,m=2655,a=1222,s=2876}").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 3));
+        assert_eq!(err.kind, ErrorKind::UnknownCategory("q".to_string()));
+    }
+
+    #[test]
+    fn test_missing_rating() {
+        let err =
+            part1("in{A}\n\n{x=787,m=2655,a=1222,s=2876}\n{x=1679,m=44,a2067,s=496}").unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 14: missing '='");
+    }
+
+    #[test]
+    fn test_condition_without_amount() {
+        let err = part1("in{x:A,R}\n\n{x=787,m=2655,a=1222,s=2876}").unwrap_err();
+        assert_eq!(err.to_string(), r#"line 1, column 5: "" isn't a number"#);
     }
 
     #[test]
@@ -650,11 +760,14 @@ hdj{m>838:A,pv}
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 167409079868000)
+        assert_eq!(part2(GIVEN_INPUT), Ok(167409079868000))
     }
 
     #[test]
     fn test_real_2() {
-        assert_eq!(part2(REAL_INPUT), 131550418841958)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part2(&input), Ok(131550418841958))
     }
 }
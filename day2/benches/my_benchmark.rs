@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
+    let games = mylib::parse(input).unwrap();
+    let bag = mylib::Bag::default();
+    c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
+    c.bench_function("part 2", |b| b.iter(|| mylib::part2(black_box(input))));
+    c.bench_function("parsing the games", |b| {
+        b.iter(|| mylib::parse(black_box(input)))
+    });
+    c.bench_function("summing possible ids", |b| {
+        b.iter(|| mylib::possible_id_sum(black_box(&games), &bag))
+    });
+    c.bench_function("summing powers", |b| {
+        b.iter(|| mylib::power_sum(black_box(&games)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
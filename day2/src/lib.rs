@@ -0,0 +1,271 @@
+pub mod stats;
+
+use aoc_common::{parse, parse_lines, InputLocation, Solution};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    Missing(char),
+    InvalidNumber(String),
+    /// The color was already counted earlier in the same round
+    RepeatedColor(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Missing(expected) => write!(f, "missing {expected:?}"),
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+            ErrorKind::RepeatedColor(color) => write!(f, "{color:?} is already in the round"),
+        }
+    }
+}
+
+impl From<parse::Missing> for ErrorKind {
+    fn from(parse::Missing(expected): parse::Missing) -> Self {
+        ErrorKind::Missing(expected)
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(s: &str) -> Result<Vec<Game>, ParseError> {
+    parse_lines(s, str::parse)
+}
+
+pub fn possible_id_sum(games: &[Game], bag: &Bag) -> u64 {
+    games
+        .iter()
+        .filter(|g| game_possible(g, bag))
+        .map(|g| g.id)
+        .sum()
+}
+
+pub fn power_sum(games: &[Game]) -> u64 {
+    let colors = colors(games);
+    games.iter().map(|game| game_power(game, &colors)).sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    /// The bag part 1 checks the games against
+    type Config = Bag;
+    type Output = u64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Vec<Game>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(games: &Vec<Game>, bag: &Bag) -> u64 {
+        possible_id_sum(games, bag)
+    }
+
+    fn part2(games: &Vec<Game>, _bag: &Bag) -> u64 {
+        power_sum(games)
+    }
+}
+
+pub fn part1(s: &str) -> Result<u64, ParseError> {
+    Ok(possible_id_sum(&parse(s)?, &Bag::default()))
+}
+
+pub fn part2(s: &str) -> Result<u64, ParseError> {
+    Ok(power_sum(&parse(s)?))
+}
+
+pub fn game_possible(game: &Game, bag: &Bag) -> bool {
+    game.rounds.iter().all(|round| bag.holds(round))
+}
+
+/// Every color drawn in any of the games
+pub fn colors(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| &game.rounds)
+        .flat_map(|round| round.cubes.keys())
+        .map(String::as_str)
+        .collect()
+}
+
+/// The fewest cubes of each color the game could have been played with
+pub fn min_bag(game: &Game) -> Bag {
+    let mut cubes = BTreeMap::new();
+    for round in &game.rounds {
+        for (color, &count) in &round.cubes {
+            let most = cubes.entry(color.clone()).or_insert(0);
+            *most = count.max(*most);
+        }
+    }
+    Bag { cubes }
+}
+
+/// The product of the fewest cubes of each of `colors`. A color the game never
+/// draws needs none, which makes the power 0
+pub fn game_power(game: &Game, colors: &BTreeSet<&str>) -> u64 {
+    let bag = min_bag(game);
+    colors.iter().map(|color| bag.count(color)).product()
+}
+
+/// The cubes shown in one handful, like `3 blue, 4 red`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Round {
+    pub cubes: BTreeMap<String, u64>,
+}
+
+impl Round {
+    pub fn count(&self, color: &str) -> u64 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let colors_str = s.split(",");
+        let mut new_guy = Self::default();
+        for color_str in colors_str {
+            let color_str = color_str.trim();
+            let (index_str, color) = parse::split_once(color_str, ' ')
+                .map_err(|err: ParseError| err.within(s, color_str))?;
+            let index = parse::number(s, index_str)?;
+            if new_guy.cubes.insert(color.to_string(), index).is_some() {
+                return Err(ParseError::new(
+                    InputLocation::of(s, color),
+                    ErrorKind::RepeatedColor(color.to_string()),
+                ));
+            }
+        }
+        Ok(new_guy)
+    }
+}
+
+/// What's in the bag, written like a round. Colors it doesn't mention aren't in it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    pub cubes: BTreeMap<String, u64>,
+}
+
+/// The bag part 1 asks about
+impl Default for Bag {
+    fn default() -> Self {
+        "12 red, 13 green, 14 blue"
+            .parse()
+            .expect("the puzzle's bag should parse")
+    }
+}
+
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Round { cubes } = s.parse()?;
+        Ok(Self { cubes })
+    }
+}
+
+impl Bag {
+    pub fn count(&self, color: &str) -> u64 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// Whether the round could have been drawn from this bag
+    pub fn holds(&self, round: &Round) -> bool {
+        round
+            .cubes
+            .iter()
+            .all(|(color, &count)| count <= self.count(color))
+    }
+}
+
+pub struct Game {
+    id: u64,
+    rounds: Vec<Round>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game_bit, rounds_bit) = parse::split_once(s, ':')?;
+        let (_, index_str) = parse::split_once(game_bit, ' ')?;
+        let index = parse::number(s, index_str)?;
+
+        let rounds: Vec<Round> = rounds_bit
+            .split(";")
+            .map(|round| {
+                round
+                    .parse()
+                    .map_err(|err: ParseError| err.within(s, round))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Game { id: index, rounds })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_given_1() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green ";
+        assert_eq!(part1(input), Ok(8))
+    }
+
+    #[test]
+    fn test_given_2() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green ";
+        assert_eq!(part2(input), Ok(2286))
+    }
+
+    #[test]
+    fn test_any_color() {
+        let games = parse("Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 purple, 1 red").unwrap();
+        assert_eq!(possible_id_sum(&games, &Bag::default()), 1);
+        let bag: Bag = "5 red, 3 purple, 1 blue".parse().unwrap();
+        assert_eq!(possible_id_sum(&games, &bag), 2);
+
+        // Game 1 draws no purple, so its power is 0
+        assert_eq!(power_sum(&games), 2);
+        let colors = BTreeSet::from(["blue", "red"]);
+        assert_eq!(game_power(&games[0], &colors), 12);
+    }
+
+    #[test]
+    fn test_bad_bag() {
+        let err = "12 red, 13".parse::<Bag>().unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 9: missing ' '");
+    }
+
+    #[test]
+    fn test_repeated_color() {
+        let err = part1("Game 1: 1 red\nGame 2: 2 green; 3 blue, 4 blue").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 2, column 28: "blue" is already in the round"#
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Looking at a whole log of games at once, rather than one game at a time
+
+use std::collections::BTreeMap;
+
+use crate::{colors, game_possible, min_bag, Bag, Game};
+
+/// The fewest cubes of each color that every game could have been played with
+pub fn min_bag_for_all(games: &[Game]) -> Bag {
+    let mut cubes = BTreeMap::new();
+    for game in games {
+        for (color, count) in min_bag(game).cubes {
+            let most = cubes.entry(color).or_insert(0);
+            *most = count.max(*most);
+        }
+    }
+    Bag { cubes }
+}
+
+/// A bag with one cube fewer of a single color, and how many games it still allows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reduction {
+    pub color: String,
+    pub count: u64,
+    pub possible: usize,
+}
+
+/// Every bag one cube smaller than `bag`, in color order
+pub fn reductions(games: &[Game], bag: &Bag) -> Vec<Reduction> {
+    bag.cubes
+        .iter()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(color, &count)| {
+            let mut smaller = bag.clone();
+            smaller.cubes.insert(color.clone(), count - 1);
+            Reduction {
+                color: color.clone(),
+                count: count - 1,
+                possible: games
+                    .iter()
+                    .filter(|game| game_possible(game, &smaller))
+                    .count(),
+            }
+        })
+        .collect()
+}
+
+/// The cube that can come out of the bag with the most games still possible.
+/// Ties go to the first color alphabetically
+pub fn best_reduction(games: &[Game], bag: &Bag) -> Option<Reduction> {
+    reductions(games, bag)
+        .into_iter()
+        .rev()
+        .max_by_key(|reduction| reduction.possible)
+}
+
+/// How many rounds showed each number of cubes of a color
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Distribution {
+    pub rounds: BTreeMap<u64, usize>,
+}
+
+impl Distribution {
+    pub fn min(&self) -> Option<u64> {
+        self.rounds.keys().next().copied()
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.rounds.keys().next_back().copied()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let rounds: usize = self.rounds.values().sum();
+        let cubes: u64 = self
+            .rounds
+            .iter()
+            .map(|(&count, &rounds)| count * rounds as u64)
+            .sum();
+        (rounds > 0).then(|| cubes as f64 / rounds as f64)
+    }
+}
+
+/// A distribution for every color in the log, over every round. A round that
+/// doesn't show a color counts as showing none of it
+pub fn distributions(games: &[Game]) -> BTreeMap<String, Distribution> {
+    let mut distributions = BTreeMap::new();
+    for color in colors(games) {
+        let mut distribution = Distribution::default();
+        for round in games.iter().flat_map(|game| &game.rounds) {
+            *distribution.rounds.entry(round.count(color)).or_insert(0) += 1;
+        }
+        distributions.insert(color.to_string(), distribution);
+    }
+    distributions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const SAMPLE_INPUT: &str = r##"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"##;
+
+    #[test]
+    fn test_given_min_bag() {
+        let games = parse(SAMPLE_INPUT).unwrap();
+
+        let bag = min_bag_for_all(&games);
+
+        assert_eq!(bag, "20 red, 13 green, 15 blue".parse().unwrap());
+        assert!(games.iter().all(|game| game_possible(game, &bag)));
+    }
+
+    #[test]
+    fn test_given_reductions() {
+        let games = parse(SAMPLE_INPUT).unwrap();
+        let bag = min_bag_for_all(&games);
+        let reductions = reductions(&games, &bag);
+
+        let possible: Vec<(&str, usize)> = reductions
+            .iter()
+            .map(|reduction| (reduction.color.as_str(), reduction.possible))
+            .collect();
+
+        assert_eq!(possible, vec![("blue", 4), ("green", 4), ("red", 4)]);
+        let best = best_reduction(&games, &bag).unwrap();
+        assert_eq!((best.color.as_str(), best.count), ("blue", 14));
+
+        let games = parse("Game 1: 1 red, 2 blue\nGame 2: 2 blue; 2 red\nGame 3: 1 red").unwrap();
+        let best = best_reduction(&games, &min_bag_for_all(&games)).unwrap();
+        assert_eq!(
+            best,
+            Reduction {
+                color: "red".to_string(),
+                count: 1,
+                possible: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_given_distributions() {
+        let games = parse(SAMPLE_INPUT).unwrap();
+
+        let distributions = distributions(&games);
+
+        let red = &distributions["red"];
+        assert_eq!(red.rounds.values().sum::<usize>(), 14);
+        assert_eq!(red.rounds[&0], 3);
+        assert_eq!((red.min(), red.max()), (Some(0), Some(20)));
+        assert_eq!(red.mean(), Some(61.0 / 14.0));
+        assert_eq!(distributions.len(), 3);
+    }
+}
@@ -0,0 +1,147 @@
+//! Random networks shaped like the puzzle's: the broadcaster starts a few binary
+//! counters made of flip-flops. Each counter has a conjunction that watches for its
+//! target, resets it, and tells the conjunction in front of `rx`
+
+use std::ops::RangeInclusive;
+
+use rand::{seq::SliceRandom, Rng};
+
+struct Network {
+    lines: Vec<String>,
+    /// `rx` gets a low pulse on the button press that's a multiple of all of these
+    #[cfg(test)]
+    targets: Vec<u64>,
+}
+
+/// Module names from a running count: `aa` to `zz`, then `aaa` and on, skipping `rx`
+#[derive(Default)]
+struct Names(usize);
+
+impl Names {
+    fn next(&mut self) -> String {
+        loop {
+            let mut id = self.0;
+            self.0 += 1;
+            let mut letters = 2;
+            while id >= 26usize.pow(letters) {
+                id -= 26usize.pow(letters);
+                letters += 1;
+            }
+            let name: String = (0..letters)
+                .rev()
+                .map(|place| char::from(b'a' + (id / 26usize.pow(place) % 26) as u8))
+                .collect();
+            if name != "rx" {
+                return name;
+            }
+        }
+    }
+}
+
+fn network(rng: &mut impl Rng, counters: usize, bits: RangeInclusive<u32>) -> Network {
+    let mut names = Names::default();
+    let last = names.next();
+    let mut lines = vec![format!("&{last} -> rx")];
+    let mut starts = vec![];
+    #[cfg(test)]
+    let mut targets = vec![];
+    for _ in 0..counters {
+        let width = rng.gen_range(bits.clone());
+        // Odd, so it's reached without a carry, at the same time as the other counters
+        let target = rng.gen_range(1 << (width - 1)..1 << width) | 1;
+        let flip_flops: Vec<String> = (0..width).map(|_| names.next()).collect();
+        let hub = names.next();
+        let inverter = names.next();
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs: Vec<&str> = flip_flops
+                .get(bit + 1)
+                .map(String::as_str)
+                .into_iter()
+                .collect();
+            if target >> bit & 1 == 1 {
+                outputs.push(&hub);
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            lines.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+        resets.push(inverter.clone());
+        lines.push(format!("&{hub} -> {}", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+        starts.push(flip_flops[0].clone());
+        #[cfg(test)]
+        targets.push(target);
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+    Network {
+        lines,
+        #[cfg(test)]
+        targets,
+    }
+}
+
+/// `size` counters, each of 4 to 12 bits
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    network(rng, size.max(1), 4..=12).lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{part1, part2};
+
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    fn lcm(a: u64, b: u64) -> u64 {
+        a / gcd(a, b) * b
+    }
+
+    #[test]
+    fn generated_networks_solve() {
+        let mut rng = StdRng::seed_from_u64(20);
+        for size in 1..6 {
+            let network = input(&mut rng, size);
+
+            assert!(part1(&network).is_ok(), "{network}");
+        }
+    }
+
+    #[test]
+    fn names_run_past_two_letters() {
+        let mut names = Names::default();
+        let names: Vec<String> = (0..26 * 26 + 1).map(|_| names.next()).collect();
+
+        assert_eq!(names[..2], ["aa", "ab"]);
+        assert!(!names.contains(&"rx".to_string()));
+        assert_eq!(names[26 * 26 - 2..], ["zz", "aaa", "aab"]);
+    }
+
+    #[test]
+    fn generated_at_the_default_size() {
+        let mut rng = StdRng::seed_from_u64(20);
+        let network = input(&mut rng, 100);
+
+        assert!(part1(&network).is_ok());
+    }
+
+    #[test]
+    fn generated_counters_meet_at_rx() {
+        let mut rng = StdRng::seed_from_u64(20);
+        for counters in 1..4 {
+            let network = network(&mut rng, counters, 3..=6);
+            let expected = network.targets.iter().copied().fold(1, lcm);
+
+            assert_eq!(part2(&network.lines.join("\n")), Ok(expected));
+        }
+    }
+}
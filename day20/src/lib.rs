@@ -1,9 +1,28 @@
+pub mod generate;
+
+use aoc_common::{parse_lines, InputLocation, Solution};
 use std::{
     collections::{HashMap, VecDeque},
-    convert::Infallible,
+    fmt::{self, Display},
     rc::Rc,
     str::FromStr,
 };
+use tracing::debug;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingArrow,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingArrow => write!(f, "missing the \"->\" before the outputs"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum PulseType {
@@ -150,7 +169,8 @@ struct PulseCounts {
     high: u64,
 }
 
-struct Modules(HashMap<ModuleIdentifier, Module>);
+#[derive(Clone)]
+pub struct Modules(HashMap<ModuleIdentifier, Module>);
 
 impl Modules {
     fn push_button(&mut self) -> Vec<Pulse> {
@@ -163,15 +183,12 @@ impl Modules {
         let mut pulse_queue: VecDeque<Pulse> = VecDeque::with_capacity(1000);
         pulse_queue.push_back(initial_pulse);
         while let Some(pulse) = pulse_queue.pop_front() {
-            match self.0.get_mut(&pulse.to) {
-                Some(target) => {
-                    let new_pulses = target.produce_pulses(&pulse);
+            if let Some(target) = self.0.get_mut(&pulse.to) {
+                let new_pulses = target.produce_pulses(&pulse);
 
-                    for new_pulse in new_pulses {
-                        pulse_queue.push_back(new_pulse);
-                    }
+                for new_pulse in new_pulses {
+                    pulse_queue.push_back(new_pulse);
                 }
-                None => (),
             };
             output.push(pulse);
         }
@@ -192,24 +209,27 @@ struct IntermediateModuleParsing {
 }
 
 impl FromStr for IntermediateModuleParsing {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once("->").unwrap();
+        let (left, right) = s
+            .split_once("->")
+            .ok_or_else(|| ParseError::new(InputLocation::of(s, s), ErrorKind::MissingArrow))?;
         let outputs: Vec<ModuleIdentifier> = right
             .trim()
             .split(",")
             .map(|identifier| ModuleIdentifier(identifier.trim().to_string().into()))
             .collect();
-        let (module_type_identifier, identifier) = if left.contains("%") {
+        let left = left.trim();
+        let (module_type_identifier, identifier) = if let Some(name) = left.strip_prefix('%') {
             (
                 ModuleTypeIdentifier::FlipFlop,
-                ModuleIdentifier(left.trim()[1..].to_string().into()),
+                ModuleIdentifier(name.to_string().into()),
             )
-        } else if left.contains("&") {
+        } else if let Some(name) = left.strip_prefix('&') {
             (
                 ModuleTypeIdentifier::Conjuction,
-                ModuleIdentifier(left.trim()[1..].to_string().into()),
+                ModuleIdentifier(name.to_string().into()),
             )
         } else {
             (
@@ -226,11 +246,10 @@ impl FromStr for IntermediateModuleParsing {
 }
 
 impl FromStr for Modules {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let intermediate_modules: Vec<IntermediateModuleParsing> =
-            s.lines().map(|line| line.parse().unwrap()).collect();
+        let intermediate_modules: Vec<IntermediateModuleParsing> = parse_lines(s, str::parse)?;
 
         let modules: Vec<Module> = intermediate_modules
             .iter()
@@ -246,80 +265,102 @@ impl FromStr for Modules {
     }
 }
 
-pub fn part1(s: &str) -> u64 {
-    let mut modules: Modules = s.parse().unwrap();
-    let mut pulse_totals = PulseCounts { low: 0, high: 0 };
+pub struct Day20;
 
-    for _ in 0..1000 {
-        let pulses = modules.push_button();
-        let lows_counts: u64 = pulses
-            .iter()
-            .filter(|pulse| pulse.pulse_type == PulseType::Low)
-            .count()
-            .try_into()
-            .unwrap();
-        let high_counts: u64 = pulses
-            .iter()
-            .filter(|pulse| pulse.pulse_type == PulseType::High)
-            .count()
-            .try_into()
-            .unwrap();
-        pulse_totals.low += lows_counts;
-        pulse_totals.high += high_counts;
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Modules;
+    type Config = ();
+    type Output = u64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Modules, ParseError> {
+        s.parse()
     }
 
-    pulse_totals.low * pulse_totals.high
-}
+    fn part1(modules: &Modules, _config: &()) -> u64 {
+        let mut modules = modules.clone();
+        let mut pulse_totals = PulseCounts { low: 0, high: 0 };
 
-pub fn part2(s: &str) -> u64 {
-    let mut modules: Modules = s.parse().unwrap();
-    let mut press_count = 0;
-    loop {
-        let pulses = modules.push_button();
-        press_count += 1;
-        if pulses.iter().any(|pulse| {
-            pulse.to == ModuleIdentifier("th".to_string().into())
-                && pulse.from == ModuleIdentifier("zl".to_string().into())
-                && pulse.pulse_type == PulseType::High
-        }) {
-            println!("zl sent a high to th at {press_count}");
-        }
-        if pulses.iter().any(|pulse| {
-            pulse.to == ModuleIdentifier("th".to_string().into())
-                && pulse.from == ModuleIdentifier("xn".to_string().into())
-                && pulse.pulse_type == PulseType::High
-        }) {
-            println!("xn sent a high to th at {press_count}");
-        }
-        if pulses.iter().any(|pulse| {
-            pulse.to == ModuleIdentifier("th".to_string().into())
-                && pulse.from == ModuleIdentifier("qn".to_string().into())
-                && pulse.pulse_type == PulseType::High
-        }) {
-            println!("qn sent a high to th at {press_count}");
-        }
-        if pulses.iter().any(|pulse| {
-            pulse.to == ModuleIdentifier("th".to_string().into())
-                && pulse.from == ModuleIdentifier("xf".to_string().into())
-                && pulse.pulse_type == PulseType::High
-        }) {
-            println!("xf sent a high to th at {press_count}");
+        for _ in 0..1000 {
+            let pulses = modules.push_button();
+            let lows_counts: u64 = pulses
+                .iter()
+                .filter(|pulse| pulse.pulse_type == PulseType::Low)
+                .count()
+                .try_into()
+                .unwrap();
+            let high_counts: u64 = pulses
+                .iter()
+                .filter(|pulse| pulse.pulse_type == PulseType::High)
+                .count()
+                .try_into()
+                .unwrap();
+            pulse_totals.low += lows_counts;
+            pulse_totals.high += high_counts;
         }
-        if pulses.iter().any(|pulse| {
-            pulse.to == ModuleIdentifier("rx".to_string().into())
-                && pulse.pulse_type == PulseType::Low
-        }) {
-            return press_count;
+
+        pulse_totals.low * pulse_totals.high
+    }
+
+    fn part2(modules: &Modules, _config: &()) -> u64 {
+        let mut modules = modules.clone();
+        let mut press_count = 0;
+        loop {
+            let pulses = modules.push_button();
+            press_count += 1;
+            if pulses.iter().any(|pulse| {
+                pulse.to == ModuleIdentifier("th".to_string().into())
+                    && pulse.from == ModuleIdentifier("zl".to_string().into())
+                    && pulse.pulse_type == PulseType::High
+            }) {
+                debug!("zl sent a high to th at {press_count}");
+            }
+            if pulses.iter().any(|pulse| {
+                pulse.to == ModuleIdentifier("th".to_string().into())
+                    && pulse.from == ModuleIdentifier("xn".to_string().into())
+                    && pulse.pulse_type == PulseType::High
+            }) {
+                debug!("xn sent a high to th at {press_count}");
+            }
+            if pulses.iter().any(|pulse| {
+                pulse.to == ModuleIdentifier("th".to_string().into())
+                    && pulse.from == ModuleIdentifier("qn".to_string().into())
+                    && pulse.pulse_type == PulseType::High
+            }) {
+                debug!("qn sent a high to th at {press_count}");
+            }
+            if pulses.iter().any(|pulse| {
+                pulse.to == ModuleIdentifier("th".to_string().into())
+                    && pulse.from == ModuleIdentifier("xf".to_string().into())
+                    && pulse.pulse_type == PulseType::High
+            }) {
+                debug!("xf sent a high to th at {press_count}");
+            }
+            if pulses.iter().any(|pulse| {
+                pulse.to == ModuleIdentifier("rx".to_string().into())
+                    && pulse.pulse_type == PulseType::Low
+            }) {
+                return press_count;
+            }
         }
     }
 }
 
+pub fn part1(s: &str) -> Result<u64, ParseError> {
+    Ok(Day20::part1(&Day20::parse(s)?, &()))
+}
+
+pub fn part2(s: &str) -> Result<u64, ParseError> {
+    Ok(Day20::part2(&Day20::parse(s)?, &()))
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::real_input;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########"broadcaster -> a, b, c
 %a -> b
 %b -> c
@@ -334,22 +375,31 @@ mod tests {
 
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(GIVEN_INPUT), 32000000)
+        assert_eq!(part1(GIVEN_INPUT), Ok(32000000))
     }
 
     #[test]
     fn test_given_1_2() {
-        assert_eq!(part1(GIVEN_INPUT_2), 11687500)
+        assert_eq!(part1(GIVEN_INPUT_2), Ok(11687500))
     }
 
     #[test]
     fn test_real_1() {
-        assert_eq!(part1(REAL_INPUT), 856482136)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(856482136))
     }
 
     #[test]
     #[ignore]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 167409079868000)
+        assert_eq!(part2(GIVEN_INPUT), Ok(167409079868000))
+    }
+
+    #[test]
+    fn test_missing_arrow() {
+        let err = part1("broadcaster -> a\n%a b").unwrap_err();
+        assert_eq!(err.location, InputLocation { line: 2, column: 1 });
     }
 }
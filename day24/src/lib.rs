@@ -1,5 +1,55 @@
+use aoc_common::{parse, parse_lines, InputLocation, Solution};
+#[cfg(not(feature = "parallel"))]
 use itertools::Itertools;
-use std::{convert::Infallible, fmt::Debug, str::FromStr};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingVelocity,
+    /// Positions and velocities have exactly three coordinates
+    WrongCoordinateCount(usize),
+    InvalidNumber(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingVelocity => write!(f, "missing the '@' before the velocity"),
+            ErrorKind::WrongCoordinateCount(count) => {
+                write!(f, "expected 3 coordinates, but found {count}")
+            }
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+        }
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+impl From<parse::WrongCount> for ErrorKind {
+    fn from(wrong: parse::WrongCount) -> Self {
+        ErrorKind::WrongCoordinateCount(wrong.found)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+fn parse_coordinates<T: FromStr>(s: &str) -> Result<(T, T, T), ParseError> {
+    let [x, y, z] = parse::exactly(s, s.split(',').map(str::trim))?;
+    Ok((
+        parse::number(s, x)?,
+        parse::number(s, y)?,
+        parse::number(s, z)?,
+    ))
+}
 
 #[derive(Debug, Clone, PartialEq)]
 struct Position<T> {
@@ -16,21 +66,13 @@ impl Position<i64> {
             z: self.x * other.y - self.y * other.x,
         }
     }
-    fn plane_intersection(&self, line: Position<i64>) -> Position<i64> {}
 }
 
-impl<T> FromStr for Position<T>
-where
-    T: FromStr,
-    <T as FromStr>::Err: Debug,
-{
-    type Err = Infallible;
+impl<T: FromStr> FromStr for Position<T> {
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values = s.split(",").collect::<Vec<_>>();
-        let x = values[0].trim().parse().unwrap();
-        let y = values[1].trim().parse().unwrap();
-        let z = values[2].trim().parse().unwrap();
+        let (x, y, z) = parse_coordinates(s)?;
         Ok(Self { x, y, z })
     }
 }
@@ -42,39 +84,34 @@ struct Velocity<T> {
     z: T,
 }
 
-impl<T> FromStr for Velocity<T>
-where
-    T: FromStr,
-    <T as FromStr>::Err: Debug,
-{
-    type Err = Infallible;
+impl<T: FromStr> FromStr for Velocity<T> {
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values = s.split(",").collect::<Vec<_>>();
-        let x = values[0].trim().parse().unwrap();
-        let y = values[1].trim().parse().unwrap();
-        let z = values[2].trim().parse().unwrap();
+        let (x, y, z) = parse_coordinates(s)?;
         Ok(Self { x, y, z })
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct Hailstone<T> {
+pub struct Hailstone<T> {
     position: Position<T>,
     velocity: Velocity<T>,
 }
 
-impl<T> FromStr for Hailstone<T>
-where
-    T: FromStr,
-    <T as FromStr>::Err: Debug,
-{
-    type Err = Infallible;
+impl<T: FromStr> FromStr for Hailstone<T> {
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (position_string, velocity_string) = s.split_once("@").unwrap();
-        let position: Position<T> = position_string.parse()?;
-        let velocity: Velocity<T> = velocity_string.parse()?;
+        let (position_string, velocity_string) = s
+            .split_once("@")
+            .ok_or_else(|| ParseError::new(InputLocation::of(s, s), ErrorKind::MissingVelocity))?;
+        let position: Position<T> = position_string
+            .parse()
+            .map_err(|err: ParseError| err.within(s, position_string))?;
+        let velocity: Velocity<T> = velocity_string
+            .parse()
+            .map_err(|err: ParseError| err.within(s, velocity_string))?;
         Ok(Self { position, velocity })
     }
 }
@@ -122,6 +159,23 @@ impl Hailstone<i64> {
     }
 }
 
+impl From<&Hailstone<i64>> for Hailstone<f64> {
+    fn from(value: &Hailstone<i64>) -> Self {
+        Hailstone {
+            position: Position {
+                x: value.position.x as f64,
+                y: value.position.y as f64,
+                z: value.position.z as f64,
+            },
+            velocity: Velocity {
+                x: value.velocity.x as f64,
+                y: value.velocity.y as f64,
+                z: value.velocity.z as f64,
+            },
+        }
+    }
+}
+
 impl Hailstone<f64> {
     fn slope_intercept_form(&self) -> SlopeIntercept<f64> {
         // We have x, y, position and velocity
@@ -134,8 +188,7 @@ impl Hailstone<f64> {
     fn time_on_path_x_y(&self, position: &Position<f64>) -> f64 {
         // let real_x = vectorX * time + positionX
         // time = (realX - positionX)/vectorX
-        let time = (position.x - self.position.x) / self.velocity.x;
-        time
+        (position.x - self.position.x) / self.velocity.x
     }
 
     fn intersection_point_x_y(&self, other: &Hailstone<f64>) -> HailstoneCollision<f64> {
@@ -174,45 +227,79 @@ impl Range {
     }
 }
 
-pub fn part1(s: &str, range: &Range) -> u64 {
-    let hailstones: Vec<Hailstone<f64>> = s.lines().map(|line| line.parse().unwrap()).collect();
-    let future_path_intersections = hailstones
-        .iter()
-        .tuple_combinations()
-        .map(|(us, them)| us.intersection_point_x_y(them));
-    let count = future_path_intersections
-        .filter(|intersection| match &intersection {
-            HailstoneCollision::Future(position) => {
-                position.x >= range.lower
-                    && position.x <= range.upper
-                    && position.y >= range.lower
-                    && position.y <= range.upper
-            }
-            _ => false,
-        })
-        .count();
-    count.try_into().unwrap()
+impl Default for Range {
+    /// The test area the real puzzle asks about
+    fn default() -> Self {
+        Self::new(200000000000000.0, 400000000000000.0)
+    }
 }
 
-pub fn part2(s: &str) -> u64 {
-    let hailstones: Vec<Hailstone<i64>> = s.lines().map(|line| line.parse().unwrap()).collect();
-    let reference = &hailstones[0];
-    let plane_definer = hailstones[1].shift_relative_to(reference);
-    let plane_point_1 = plane_definer.at_time(0);
-    let plane_point_2 = plane_definer.at_time(1);
-    let plane_normal = plane_point_1.cross_product(&plane_point_2);
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Vec<Hailstone<i64>>;
+    type Config = Range;
+    type Output = u64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(s, str::parse)
+    }
+
+    fn part1(hailstones: &Self::Input, range: &Range) -> u64 {
+        let hailstones: Vec<Hailstone<f64>> = hailstones.iter().map(Hailstone::from).collect();
+        #[cfg(feature = "parallel")]
+        let pairs = hailstones
+            .par_iter()
+            .enumerate()
+            .flat_map_iter(|(i, us)| hailstones[i + 1..].iter().map(move |them| (us, them)));
+        #[cfg(not(feature = "parallel"))]
+        let pairs = hailstones.iter().tuple_combinations();
+        let future_path_intersections = pairs.map(|(us, them)| us.intersection_point_x_y(them));
+        let count = future_path_intersections
+            .filter(|intersection| match &intersection {
+                HailstoneCollision::Future(position) => {
+                    position.x >= range.lower
+                        && position.x <= range.upper
+                        && position.y >= range.lower
+                        && position.y <= range.upper
+                }
+                _ => false,
+            })
+            .count();
+        count.try_into().unwrap()
+    }
 
-    let intersection_1 = hailstones[2].shift_relative_to(reference);
-    let intersection_2 = hailstones[3].shift_relative_to(reference);
-    0
+    fn part2(hailstones: &Self::Input, _range: &Range) -> u64 {
+        let [reference, plane_definer, first, second, ..] = hailstones.as_slice() else {
+            return 0;
+        };
+        let plane_definer = plane_definer.shift_relative_to(reference);
+        let plane_point_1 = plane_definer.at_time(0);
+        let plane_point_2 = plane_definer.at_time(1);
+        let _plane_normal = plane_point_1.cross_product(&plane_point_2);
+
+        let _intersection_1 = first.shift_relative_to(reference);
+        let _intersection_2 = second.shift_relative_to(reference);
+        0
+    }
+}
+
+pub fn part1(s: &str, range: &Range) -> Result<u64, ParseError> {
+    Ok(Day24::part1(&Day24::parse(s)?, range))
+}
+
+pub fn part2(s: &str) -> Result<u64, ParseError> {
+    Ok(Day24::part2(&Day24::parse(s)?, &Range::default()))
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_common::real_input;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
     const GIVEN_INPUT: &str = r#########"19, 13, 30 @ -2,  1, -2
 18, 19, 22 @ -1, -1, -2
 20, 25, 34 @ -2, -2, -4
@@ -225,18 +312,32 @@ mod tests {
             lower: 7.0,
             upper: 27.0,
         };
-        assert_eq!(part1(GIVEN_INPUT, &range), 2)
+        assert_eq!(part1(GIVEN_INPUT, &range), Ok(2))
     }
 
     #[test]
     fn test_actual_1() {
+        let Some(input) = real_input!() else {
+            return;
+        };
         let real_range = Range::new(200000000000000.0, 400000000000000.0);
-        assert_eq!(part1(REAL_INPUT, &real_range), 25810)
+        assert_eq!(part1(&input, &real_range), Ok(25810))
     }
 
     #[test]
     #[ignore]
     fn test_given_2() {
-        assert_eq!(part2(GIVEN_INPUT), 167409079868000)
+        assert_eq!(part2(GIVEN_INPUT), Ok(167409079868000))
+    }
+
+    #[test]
+    fn test_too_few_hailstones() {
+        assert!(part2("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2").is_ok());
+    }
+
+    #[test]
+    fn test_bad_velocity() {
+        let err = part2("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, q, -2").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 18: \"q\" isn't a number");
     }
 }
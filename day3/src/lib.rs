@@ -0,0 +1,340 @@
+use aoc_common::{InputLocation, Solution};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    time::Instant,
+};
+use tracing::debug;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidNumber(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Position {
+    row: i64,
+    col: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Symbol {
+    position: Position,
+    char: char,
+}
+
+impl Symbol {
+    fn to_gear(&self, numbers: &[Number]) -> Option<Gear> {
+        if self.char == '*' {
+            let adjacent_numbers: Vec<&Number> = numbers
+                .iter()
+                .filter(|n| number_and_symbol_are_adjacent(n, self))
+                .collect();
+            if adjacent_numbers.len() != 2 {
+                None
+            } else {
+                Some(Gear {
+                    position: self.position.clone(),
+                    part1: adjacent_numbers[0].clone(),
+                    part2: adjacent_numbers[1].clone(),
+                })
+            }
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Gear {
+    position: Position,
+    part1: Number,
+    part2: Number,
+}
+
+impl Gear {
+    fn ratio(&self) -> i64 {
+        self.part1.value * self.part2.value
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Number {
+    value: i64,
+    start: Position,
+    end: Position,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+}
+
+fn line_to_schematic(line: &str, row: i64) -> Result<Schematic, ParseError> {
+    let mut new_schematic = Schematic {
+        numbers: vec![],
+        symbols: vec![],
+    };
+    let mut chars_enumerated = line.chars().enumerate().peekable();
+    while let Some((col, char)) = chars_enumerated.next() {
+        if char == '.' {
+            continue;
+        } else if char.is_numeric() {
+            let mut number_string = char.to_string();
+            let start_col: i64 = col.try_into().unwrap();
+            let mut end_col = start_col;
+            while let Some((new_end, new_char)) = chars_enumerated.peek() {
+                if !new_char.is_numeric() {
+                    break;
+                }
+                end_col = (*new_end).try_into().unwrap();
+                number_string.push(*new_char);
+                chars_enumerated.next();
+            }
+            let value = number_string.parse().map_err(|_| {
+                ParseError::new(
+                    InputLocation::new(0, col),
+                    ErrorKind::InvalidNumber(number_string.clone()),
+                )
+            })?;
+            new_schematic.numbers.push(Number {
+                value,
+                start: Position {
+                    row,
+                    col: start_col,
+                },
+                end: Position { row, col: end_col },
+            });
+        } else {
+            // We must be a symbol at this point
+            new_schematic.symbols.push(Symbol {
+                char,
+                position: Position {
+                    row,
+                    col: col.try_into().unwrap(),
+                },
+            })
+        }
+    }
+    Ok(new_schematic)
+}
+
+#[cfg(test)]
+fn combine_schematic(s1: Schematic, s2: Schematic) -> Schematic {
+    Schematic {
+        numbers: [s1.numbers, s2.numbers].concat(),
+        symbols: [s1.symbols, s2.symbols].concat(),
+    }
+}
+
+#[cfg(test)]
+fn flatten_schematic_slow(schematics: Vec<Schematic>) -> Schematic {
+    let mut schematic_list = schematics.into_iter();
+    let first = schematic_list.next().unwrap();
+    let schematic: Schematic = schematic_list.fold(first, combine_schematic);
+    schematic
+}
+
+fn flatten_schematic(schematics: Vec<Schematic>) -> Schematic {
+    let mut new_schematic = Schematic {
+        numbers: vec![],
+        symbols: vec![],
+    };
+    for mut schematic in schematics.into_iter() {
+        new_schematic.numbers.append(&mut schematic.numbers);
+        new_schematic.symbols.append(&mut schematic.symbols);
+    }
+    new_schematic
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let start = Instant::now();
+        let schematics: Vec<Schematic> = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line_to_schematic(line, index.try_into().unwrap()).map_err(|err| err.shifted(index))
+            })
+            .collect::<Result<_, _>>()?;
+        debug!("made the individual schematics in {:?}", start.elapsed());
+        let schematic = flatten_schematic(schematics);
+        debug!("folded in {:?}", start.elapsed());
+        Ok(schematic)
+    }
+}
+
+fn number_and_symbol_are_adjacent(number: &Number, symbol: &Symbol) -> bool {
+    (symbol.position.row >= number.start.row - 1 && symbol.position.row <= number.start.row + 1)
+        && (symbol.position.col >= number.start.col - 1
+            && symbol.position.col <= number.end.col + 1)
+}
+
+fn number_is_adjacent_to_any_symbol(number: &Number, symbols: &[Symbol]) -> bool {
+    symbols
+        .iter()
+        .any(|symbol| number_and_symbol_are_adjacent(number, symbol))
+}
+
+pub fn parse(s: &str) -> Result<Schematic, ParseError> {
+    s.parse()
+}
+
+pub fn part_number_sum(schematic: &Schematic) -> i64 {
+    schematic
+        .numbers
+        .iter()
+        .filter(|x| number_is_adjacent_to_any_symbol(x, &schematic.symbols))
+        .map(|x| x.value)
+        .sum()
+}
+
+pub fn gear_ratio_sum(schematic: &Schematic) -> i64 {
+    schematic
+        .symbols
+        .iter()
+        .filter_map(|x| x.to_gear(&schematic.numbers))
+        .map(|g| g.ratio())
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Schematic;
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Schematic, ParseError> {
+        parse(s)
+    }
+
+    fn part1(schematic: &Schematic, _config: &()) -> i64 {
+        part_number_sum(schematic)
+    }
+
+    fn part2(schematic: &Schematic, _config: &()) -> i64 {
+        gear_ratio_sum(schematic)
+    }
+}
+
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    let start = Instant::now();
+    let schematic = parse(s)?;
+    debug!("elapsed {:?} to parse schematic", start.elapsed());
+    let result = part_number_sum(&schematic);
+
+    debug!("elapsed {:?} to do the math", start.elapsed());
+    Ok(result)
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(gear_ratio_sum(&parse(s)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn basic_line_to_schematic() {
+        let input = "617*.#.5..";
+        let expected_schematic = Schematic {
+            numbers: vec![
+                Number {
+                    value: 617,
+                    start: Position { row: 0, col: 0 },
+                    end: Position { row: 0, col: 2 },
+                },
+                Number {
+                    value: 5,
+                    start: Position { row: 0, col: 7 },
+                    end: Position { row: 0, col: 7 },
+                },
+            ],
+            symbols: vec![
+                Symbol {
+                    position: Position { row: 0, col: 3 },
+                    char: '*',
+                },
+                Symbol {
+                    position: Position { row: 0, col: 5 },
+                    char: '#',
+                },
+            ],
+        };
+
+        let actual_schematic = line_to_schematic(input, 0);
+
+        assert_eq!(Ok(expected_schematic), actual_schematic);
+    }
+
+    #[test]
+    fn test_given_1() {
+        let input = " 467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        assert_eq!(part1(input), Ok(4361))
+    }
+
+    #[test]
+    fn test_given_2() {
+        let input = " 467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        assert_eq!(part2(input), Ok(467835))
+    }
+
+    #[test]
+    fn test_number_too_big() {
+        let input = "..*..\n.99999999999999999999.";
+        let err = part1(input).unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn flattening_keeps_every_line(lines in prop::collection::vec("[0-9.*#+]{0,12}", 1..8)) {
+            let schematics: Vec<Schematic> = lines
+                .iter()
+                .enumerate()
+                .map(|(row, line)| line_to_schematic(line, row as i64).unwrap())
+                .collect();
+
+            prop_assert_eq!(
+                flatten_schematic(schematics.clone()),
+                flatten_schematic_slow(schematics)
+            );
+        }
+    }
+}
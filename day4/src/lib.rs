@@ -0,0 +1,170 @@
+use aoc_common::{parse, parse_lines, Solution};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    Missing(char),
+    InvalidNumber(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Missing(expected) => write!(f, "missing {expected:?}"),
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+        }
+    }
+}
+
+impl From<parse::Missing> for ErrorKind {
+    fn from(parse::Missing(expected): parse::Missing) -> Self {
+        ErrorKind::Missing(expected)
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(s: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(s, str::parse)
+}
+
+pub fn total_points(cards: &[Card]) -> i64 {
+    cards
+        .iter()
+        .map(|c| {
+            let winning_count: i64 = c.winning_numbers().len().try_into().unwrap();
+            if winning_count > 0 {
+                2_i64.pow((winning_count - 1).try_into().unwrap())
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub fn total_cards(cards: &[Card]) -> i64 {
+    let mut won_cards: Vec<i64> = vec![0; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let won_card_count = won_cards[i];
+        let total_card_count = 1 + won_card_count;
+        let new_cards_won = card.winning_numbers().len();
+        for j in 1..=new_cards_won {
+            won_cards[i + j] += total_card_count;
+        }
+    }
+    let card_count: i64 = cards.len().try_into().unwrap();
+    card_count + won_cards.iter().sum::<i64>()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Vec<Card>, ParseError> {
+        parse(s)
+    }
+
+    fn part1(cards: &Vec<Card>, _config: &()) -> i64 {
+        total_points(cards)
+    }
+
+    fn part2(cards: &Vec<Card>, _config: &()) -> i64 {
+        total_cards(cards)
+    }
+}
+
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(total_points(&parse(s)?))
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(total_cards(&parse(s)?))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    winning_numbers: Vec<u64>,
+    my_numbers: Vec<u64>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (before_divider, my_numbers_str) = parse::split_once(s, '|')?;
+        let (_card, winning_numbers_str) = parse::header(before_divider)?;
+        let winning_numbers = parse::numbers(winning_numbers_str)
+            .map_err(|err: ParseError| err.within(s, winning_numbers_str))?;
+        let my_numbers = parse::numbers(my_numbers_str)
+            .map_err(|err: ParseError| err.within(s, my_numbers_str))?;
+
+        Ok(Card {
+            winning_numbers,
+            my_numbers,
+        })
+    }
+}
+
+impl Card {
+    fn winning_numbers(&self) -> Vec<u64> {
+        self.my_numbers
+            .iter()
+            .filter(|my| self.winning_numbers.iter().any(|other| *my == other))
+            .map(|i| i.to_owned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = r##"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"##;
+
+    #[test]
+    fn parse_card() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let expected_card = Card {
+            winning_numbers: vec![41, 48, 83, 86, 17],
+            my_numbers: vec![83, 86, 6, 31, 17, 9, 48, 53],
+        };
+
+        let actual_card: Card = input.parse().unwrap();
+
+        assert_eq!(expected_card, actual_card);
+    }
+    #[test]
+    fn test_given_1() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(13))
+    }
+
+    #[test]
+    fn test_given_2() {
+        assert_eq!(part2(SAMPLE_INPUT), Ok(30))
+    }
+
+    #[test]
+    fn test_missing_divider() {
+        let err = part1("Card 1: 41 48 | 83 86\nCard 2: 13 32 61 30").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: missing '|'");
+    }
+}
@@ -0,0 +1,574 @@
+use aoc_common::{parse, InputLocation, Solution};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    vec,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidNumber(String),
+    /// A mapping line needs exactly three numbers
+    WrongNumberCount(usize),
+    NoSeeds,
+    /// Part 2 reads the seeds as start and length pairs
+    UnpairedSeed(usize),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+            ErrorKind::WrongNumberCount(count) => {
+                write!(f, "a mapping needs 3 numbers, but this has {count}")
+            }
+            ErrorKind::NoSeeds => write!(f, "there are no seeds"),
+            ErrorKind::UnpairedSeed(count) => {
+                write!(f, "seeds come in pairs, but there are {count}")
+            }
+        }
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+impl From<parse::WrongCount> for ErrorKind {
+    fn from(wrong: parse::WrongCount) -> Self {
+        ErrorKind::WrongNumberCount(wrong.found)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Mapping {
+    destination_start: i64,
+    source_start: i64,
+    tunnel_length: i64,
+}
+
+impl FromStr for Mapping {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [destination_start, source_start, tunnel_length] =
+            parse::exactly(s, s.split_whitespace())?;
+        Ok(Mapping {
+            destination_start: parse::number(s, destination_start)?,
+            source_start: parse::number(s, source_start)?,
+            tunnel_length: parse::number(s, tunnel_length)?,
+        })
+    }
+}
+
+impl Mapping {
+    #[inline]
+    fn source_end(&self) -> i64 {
+        self.source_start + self.tunnel_length - 1
+    }
+
+    fn jump_distance(&self) -> i64 {
+        self.destination_start - self.source_start
+    }
+
+    fn get_destination_if_in_range(&self, i: i64) -> Option<i64> {
+        let max = self.source_start + self.tunnel_length - 1;
+        if i < self.source_start || i > max {
+            None
+        } else {
+            Some(i - self.source_start + self.destination_start)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct MappingBlock {
+    maps: Vec<Mapping>,
+}
+
+impl MappingBlock {
+    fn get_destination(&self, source: i64) -> i64 {
+        self.maps
+            .iter()
+            .find_map(|map| map.get_destination_if_in_range(source))
+            .unwrap_or(source)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+struct SeedRange {
+    start: i64,
+    end: i64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct SplitSeedRange {
+    originals: Vec<SeedRange>,
+    splits: Vec<SeedRange>,
+}
+
+impl SplitSeedRange {
+    fn combine(self, other: Self) -> Self {
+        Self {
+            originals: [self.originals, other.originals].concat(),
+            splits: [self.splits, other.splits].concat(),
+        }
+    }
+}
+
+impl SeedRange {
+    fn new(start: i64, length: i64) -> Self {
+        Self {
+            start,
+            end: start + length - 1,
+        }
+    }
+    fn from_start_end(start: i64, end: i64) -> Option<Self> {
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    fn split_from_mapping(self, mapping: &Mapping) -> SplitSeedRange {
+        if (self.end < mapping.source_start) || (self.start > mapping.source_end()) {
+            SplitSeedRange {
+                originals: vec![self],
+                splits: vec![],
+            }
+        } else {
+            // Lower is all of the items on the bottom that do not get mapped
+            let lower_start = self.start;
+            let lower_end = mapping.source_start - 1;
+            let lower = SeedRange::from_start_end(lower_start, lower_end);
+
+            // Middle is all of the elements getting mapped
+            let middle_start = self.start.max(mapping.source_start);
+            let middle_end = self.end.min(mapping.source_end());
+            let middle = SeedRange::from_start_end(
+                middle_start + mapping.jump_distance(),
+                middle_end + mapping.jump_distance(),
+            );
+
+            // Upper is all of the items on the top that do not get mapped
+            let upper_start = mapping.source_end() + 1;
+            let upper_end = self.end;
+            let upper = SeedRange::from_start_end(upper_start, upper_end);
+
+            SplitSeedRange {
+                originals: vec![lower, upper].into_iter().flatten().collect(),
+                splits: vec![middle].into_iter().flatten().collect(),
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct SeedRangesInput {
+    seed_ranges: SeedRanges,
+    mappings: Mappings,
+}
+
+impl From<SeedsInput> for SeedRangesInput {
+    fn from(value: SeedsInput) -> Self {
+        let seed_ranges: SeedRanges = SeedRanges(
+            value
+                .seeds
+                .chunks_exact(2)
+                .map(|slice| SeedRange::new(slice[0], slice[1]))
+                .collect(),
+        );
+        Self {
+            seed_ranges,
+            mappings: value.mappings,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct SeedRanges(Vec<SeedRange>);
+impl SeedRanges {
+    fn split_all_mapping_block(self, mapping_block: &MappingBlock) -> Self {
+        let ranges: Vec<SeedRange> = self
+            .0
+            .into_iter()
+            .map(|range| {
+                mapping_block.maps.iter().fold(
+                    SplitSeedRange {
+                        originals: vec![range],
+                        splits: vec![],
+                    },
+                    |split_range, mapping| {
+                        let split_ranges: Vec<SplitSeedRange> = split_range
+                            .originals
+                            .into_iter()
+                            .map(|range| range.split_from_mapping(mapping))
+                            .collect();
+
+                        let split_range: SplitSeedRange = split_ranges.into_iter().fold(
+                            SplitSeedRange {
+                                originals: vec![],
+                                splits: split_range.splits,
+                            },
+                            |acc, r| acc.combine(r),
+                        );
+                        split_range
+                    },
+                )
+            })
+            .flat_map(|split_range| [split_range.originals, split_range.splits].concat())
+            .collect();
+        SeedRanges(ranges)
+    }
+    fn lowest_value(&self) -> i64 {
+        self.0
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .expect("Don't call lowest value with nothing!")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Mappings(Vec<MappingBlock>);
+impl Mappings {
+    fn get_location(&self, seed: i64) -> i64 {
+        self.0
+            .iter()
+            .fold(seed, |i, mapping_block| mapping_block.get_destination(i))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SeedsInput {
+    seeds: Vec<i64>,
+    // We need to get through every mapping to get to the end
+    mappings: Mappings,
+}
+
+impl FromStr for SeedsInput {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut grouped_lines = parse::sections(s);
+        let seeds_line = grouped_lines.next().unwrap_or(s);
+        let seeds_str = seeds_line
+            .split_once(':')
+            .map_or(seeds_line, |(_, seeds)| seeds);
+        let seeds: Vec<i64> =
+            parse::numbers(seeds_str).map_err(|err: ParseError| err.within(s, seeds_str))?;
+        let wrong_seeds = match seeds.len() {
+            0 => Some(ErrorKind::NoSeeds),
+            count if count % 2 == 1 => Some(ErrorKind::UnpairedSeed(count)),
+            _ => None,
+        };
+        if let Some(kind) = wrong_seeds {
+            return Err(ParseError::new(InputLocation::of(s, seeds_str), kind));
+        }
+        let mappings: Vec<MappingBlock> = grouped_lines
+            .map(|group| {
+                Ok(MappingBlock {
+                    maps: group
+                        .lines()
+                        .skip(1)
+                        .map(|line| line.parse().map_err(|err: ParseError| err.within(s, line)))
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(SeedsInput {
+            seeds,
+            mappings: Mappings(mappings),
+        })
+    }
+}
+
+pub fn parse(s: &str) -> Result<SeedsInput, ParseError> {
+    s.parse()
+}
+
+pub fn lowest_location(input: &SeedsInput) -> i64 {
+    input
+        .seeds
+        .iter()
+        .map(|seed| input.mappings.get_location(*seed))
+        .min()
+        .expect("Don't call me without seeds")
+}
+
+/// Like [`lowest_location`], but with the seeds read as start and length pairs
+pub fn lowest_range_location(input: &SeedsInput) -> i64 {
+    let input: SeedRangesInput = input.clone().into();
+    let final_seed_ranges = input
+        .mappings
+        .0
+        .iter()
+        .fold(input.seed_ranges, |ranges, mapping_block| {
+            ranges.split_all_mapping_block(mapping_block)
+        });
+    final_seed_ranges.lowest_value()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = SeedsInput;
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<SeedsInput, ParseError> {
+        parse(s)
+    }
+
+    fn part1(input: &SeedsInput, _config: &()) -> i64 {
+        lowest_location(input)
+    }
+
+    fn part2(input: &SeedsInput, _config: &()) -> i64 {
+        lowest_range_location(input)
+    }
+}
+
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(lowest_location(&parse(s)?))
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(lowest_range_location(&parse(s)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::InputLocation;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    use super::*;
+
+    const SAMPLE_INPUT: &str = r##"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"##;
+
+    #[test]
+    fn parse_mapping() {
+        let input = "0 15 37";
+        let expected_mapping = Mapping {
+            destination_start: 0,
+            source_start: 15,
+            tunnel_length: 37,
+        };
+
+        let actual_mapping: Mapping = input.parse().unwrap();
+
+        assert_eq!(expected_mapping, actual_mapping);
+    }
+
+    #[test]
+    fn parse_short_mapping() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n";
+
+        let err = input.parse::<SeedsInput>().unwrap_err();
+
+        assert_eq!(err.location, InputLocation::new(4, 0));
+        assert_eq!(err.kind, ErrorKind::WrongNumberCount(2));
+    }
+
+    #[test]
+    fn parse_without_seeds() {
+        let err = "".parse::<SeedsInput>().unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NoSeeds);
+
+        let err = "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n"
+            .parse::<SeedsInput>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: seeds come in pairs, but there are 3"
+        );
+    }
+
+    #[test]
+    fn mapping() {
+        let mapping = Mapping {
+            destination_start: 50,
+            source_start: 98,
+            tunnel_length: 2,
+        };
+        let input = 99;
+        let expected = 51;
+
+        let val = mapping.get_destination_if_in_range(input);
+
+        assert_eq!(Some(expected), val)
+    }
+
+    #[test]
+    fn test_given_1() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(35))
+    }
+
+    #[test]
+    fn test_split_lower_overlap() {
+        let ranges = SeedRanges(vec![SeedRange { start: 0, end: 10 }]);
+        let block = MappingBlock {
+            maps: vec![Mapping {
+                source_start: -1,
+                destination_start: 1,
+                tunnel_length: 4,
+            }],
+        };
+        let expected_ranges = SeedRanges(vec![
+            SeedRange { start: 2, end: 4 },
+            SeedRange { start: 3, end: 10 },
+        ]);
+
+        let actual_ranges = ranges.split_all_mapping_block(&block);
+
+        assert!(ranges_equal(expected_ranges, actual_ranges));
+    }
+
+    #[test]
+    fn test_split_no_overlap() {
+        let ranges = SeedRanges(vec![SeedRange { start: 0, end: 10 }]);
+        let block = MappingBlock {
+            maps: vec![Mapping {
+                source_start: 11,
+                destination_start: 1,
+                tunnel_length: 4,
+            }],
+        };
+        let expected_ranges = ranges.clone();
+
+        let actual_ranges = ranges.split_all_mapping_block(&block);
+
+        assert!(ranges_equal(expected_ranges, actual_ranges));
+    }
+
+    #[test]
+    fn test_split_real() {
+        let ranges = SeedRanges(vec![SeedRange { start: 74, end: 87 }]);
+        let block = MappingBlock {
+            maps: vec![Mapping {
+                source_start: 77,
+                destination_start: 45,
+                tunnel_length: 23,
+            }],
+        };
+        let expected_ranges = SeedRanges(vec![
+            SeedRange { start: 74, end: 76 },
+            SeedRange { start: 45, end: 55 },
+        ]);
+
+        let actual_ranges = ranges.split_all_mapping_block(&block);
+
+        assert!(ranges_equal(expected_ranges, actual_ranges));
+    }
+
+    #[test]
+    fn test_split_one_overlap() {
+        let ranges = SeedRanges(vec![SeedRange { start: 0, end: 10 }]);
+        let block = MappingBlock {
+            maps: vec![Mapping {
+                source_start: 10,
+                destination_start: 1,
+                tunnel_length: 4,
+            }],
+        };
+        let expected_ranges = SeedRanges(vec![
+            SeedRange { start: 0, end: 9 },
+            SeedRange { start: 1, end: 1 },
+        ]);
+
+        let actual_ranges = ranges.split_all_mapping_block(&block);
+
+        assert!(ranges_equal(expected_ranges, actual_ranges));
+    }
+
+    #[test]
+    fn test_given_2() {
+        assert_eq!(part2(SAMPLE_INPUT), Ok(46))
+    }
+
+    #[test]
+    fn test_given_2_fast() {
+        assert_eq!(part2(SAMPLE_INPUT), Ok(46))
+    }
+
+    fn ranges_equal(expected: SeedRanges, actual: SeedRanges) -> bool {
+        let expected_set: HashSet<SeedRange> = expected.0.into_iter().collect();
+        let actual_set: HashSet<SeedRange> = actual.0.into_iter().collect();
+        expected_set == actual_set
+    }
+
+    fn seeds_and_mappings() -> impl Strategy<Value = SeedsInput> {
+        let seeds = prop::collection::vec((0..100_i64, 1..20_i64), 1..4);
+        let mapping = (0..100_i64, 0..100_i64, 1..30_i64).prop_map(
+            |(destination_start, source_start, tunnel_length)| Mapping {
+                destination_start,
+                source_start,
+                tunnel_length,
+            },
+        );
+        let block = prop::collection::vec(mapping, 0..4).prop_map(|maps| MappingBlock { maps });
+        (seeds, prop::collection::vec(block, 1..4)).prop_map(|(seeds, blocks)| SeedsInput {
+            seeds: seeds
+                .into_iter()
+                .flat_map(|(start, length)| [start, length])
+                .collect(),
+            mappings: Mappings(blocks),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn ranges_find_the_lowest_seed(input in seeds_and_mappings()) {
+            let every_seed = input
+                .seeds
+                .chunks_exact(2)
+                .flat_map(|pair| pair[0]..pair[0] + pair[1])
+                .map(|seed| input.mappings.get_location(seed))
+                .min()
+                .unwrap();
+
+            prop_assert_eq!(lowest_range_location(&input), every_seed);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
+    let races = mylib::parse(input).unwrap();
+    let race = mylib::parse_single_race(input).unwrap();
+    c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
+    c.bench_function("part 2", |b| b.iter(|| mylib::part2(black_box(input))));
+    c.bench_function("parsing the races", |b| {
+        b.iter(|| mylib::parse(black_box(input)))
+    });
+    c.bench_function("parsing the single race", |b| {
+        b.iter(|| mylib::parse_single_race(black_box(input)))
+    });
+    c.bench_function("winning the races", |b| {
+        b.iter(|| mylib::winning_ways_product(black_box(&races)))
+    });
+    c.bench_function("winning the single race", |b| {
+        b.iter(|| mylib::winning_ways(black_box(&race)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
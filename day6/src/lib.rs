@@ -0,0 +1,182 @@
+use aoc_common::{parse, InputLocation, Solution};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingDistances,
+    Missing(char),
+    InvalidNumber(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingDistances => write!(f, "missing the line of distances"),
+            ErrorKind::Missing(expected) => write!(f, "missing {expected:?}"),
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+        }
+    }
+}
+
+impl From<parse::Missing> for ErrorKind {
+    fn from(parse::Missing(expected): parse::Missing) -> Self {
+        ErrorKind::Missing(expected)
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+/// The line of times and the line of distances
+fn split_lines(s: &str) -> Result<(&str, &str), ParseError> {
+    let mut lines = s.lines();
+    let times = lines.next().unwrap_or(s);
+    let distances = lines.next().ok_or_else(|| {
+        ParseError::new(
+            InputLocation::of(s, &s[s.len()..]),
+            ErrorKind::MissingDistances,
+        )
+    })?;
+    Ok((times, distances))
+}
+
+pub struct RaceRecord {
+    total_time: i64,
+    record_distance: i64,
+}
+
+impl RaceRecord {
+    fn compute_total_distance(&self, holding_time: i64) -> i64 {
+        let speed = holding_time;
+        (self.total_time - holding_time) * speed
+    }
+    fn find_all_winning_amounts(&self) -> Vec<i64> {
+        (0..self.total_time)
+            .filter(|&holding_time| {
+                self.compute_total_distance(holding_time) > self.record_distance
+            })
+            .collect()
+    }
+}
+
+pub struct RaceRecords(Vec<RaceRecord>);
+
+impl FromStr for RaceRecords {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (times_line, distances_line) = split_lines(s)?;
+        let numbers = |line| {
+            let (_, numbers) =
+                parse::header(line).map_err(|err: ParseError| err.within(s, line))?;
+            parse::numbers(numbers).map_err(|err: ParseError| err.within(s, numbers))
+        };
+        let times: Vec<i64> = numbers(times_line)?;
+        let distances: Vec<i64> = numbers(distances_line)?;
+        let races_vec = times
+            .into_iter()
+            .zip(distances)
+            .map(|(total_time, record_distance)| RaceRecord {
+                total_time,
+                record_distance,
+            });
+        Ok(RaceRecords(races_vec.collect()))
+    }
+}
+
+pub fn parse(s: &str) -> Result<RaceRecords, ParseError> {
+    s.parse()
+}
+
+/// Part 2 reads the same lines as a single race
+pub fn parse_single_race(s: &str) -> Result<RaceRecord, ParseError> {
+    let (times, distances) = split_lines(s)?;
+    // The numbers are split by kerning, so join them back together
+    let parse_joined = |line: &str| {
+        let (_, numbers) = parse::header(line).map_err(|err: ParseError| err.within(s, line))?;
+        let joined: String = numbers.chars().filter(|c| !c.is_whitespace()).collect();
+        joined.parse().map_err(|_| {
+            ParseError::new(
+                InputLocation::of(s, numbers),
+                ErrorKind::InvalidNumber(joined),
+            )
+        })
+    };
+
+    Ok(RaceRecord {
+        total_time: parse_joined(times)?,
+        record_distance: parse_joined(distances)?,
+    })
+}
+
+pub fn winning_ways_product(races: &RaceRecords) -> i64 {
+    races.0.iter().map(winning_ways).product()
+}
+
+pub fn winning_ways(race: &RaceRecord) -> i64 {
+    race.find_all_winning_amounts().len().try_into().unwrap()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    /// The races for part 1, and the same lines read as part 2's single race
+    type Input = (RaceRecords, RaceRecord);
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse(s)?, parse_single_race(s)?))
+    }
+
+    fn part1((races, _): &Self::Input, _config: &()) -> i64 {
+        winning_ways_product(races)
+    }
+
+    fn part2((_, race): &Self::Input, _config: &()) -> i64 {
+        winning_ways(race)
+    }
+}
+
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(winning_ways_product(&parse(s)?))
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(winning_ways(&parse_single_race(s)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = r##"Time:      7  15   30
+Distance:  9  40  200"##;
+
+    #[test]
+    fn test_given_1() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(288))
+    }
+
+    #[test]
+    fn test_given_2() {
+        assert_eq!(part2(SAMPLE_INPUT), Ok(71503))
+    }
+
+    #[test]
+    fn test_missing_distances() {
+        let err = part1("Time:      7  15   30").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(0, 21));
+        assert_eq!(err.kind, ErrorKind::MissingDistances);
+    }
+}
@@ -0,0 +1,20 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
+    let hands = mylib::parse(input).unwrap();
+    c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
+    c.bench_function("part 2", |b| b.iter(|| mylib::part2(black_box(input))));
+    c.bench_function("parsing the hands", |b| {
+        b.iter(|| mylib::parse(black_box(input)))
+    });
+    c.bench_function("ranking the hands", |b| {
+        b.iter(|| mylib::total_winnings(black_box(&hands)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
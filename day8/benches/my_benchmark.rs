@@ -0,0 +1,20 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
+    let (directions, maps) = mylib::parse(input).unwrap();
+    c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
+    // Part 2 walks every ghost in lockstep and doesn't finish on a real input
+    c.bench_function("parsing the maps", |b| {
+        b.iter(|| mylib::parse(black_box(input)))
+    });
+    c.bench_function("walking to ZZZ", |b| {
+        b.iter(|| mylib::steps_to_zzz(black_box(&directions), black_box(&maps)))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
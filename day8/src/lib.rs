@@ -0,0 +1,290 @@
+use aoc_common::{parse, parse_lines, InputLocation, Solution};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+use tracing::{debug, trace};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnknownDirection(String),
+    MissingNodes,
+    Missing(&'static str),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownDirection(direction) => write!(f, "unknown direction {direction:?}"),
+            ErrorKind::MissingNodes => write!(f, "missing the blank line before the nodes"),
+            ErrorKind::Missing(expected) => write!(f, "missing {expected:?}"),
+        }
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
+
+pub fn parse(s: &str) -> Result<(Directions, Maps), ParseError> {
+    let (directions_str, maps_str) = parse::two_sections(s, ErrorKind::MissingNodes)?;
+    let directions: Directions = directions_str.parse()?;
+    let maps: Maps = maps_str
+        .parse()
+        .map_err(|err: ParseError| err.within(s, maps_str))?;
+    Ok((directions, maps))
+}
+
+#[derive(Debug, Clone)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "R" => Self::Right,
+            "L" => Self::Left,
+            _ => {
+                return Err(ParseError::new(
+                    InputLocation::of(s, s),
+                    ErrorKind::UnknownDirection(s.to_string()),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Directions {
+    backing: Vec<Direction>,
+    current_index: usize,
+}
+
+impl FromStr for Directions {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Directions {
+            backing: s
+                .char_indices()
+                .map(|(i, c)| {
+                    let direction = &s[i..i + c.len_utf8()];
+                    direction
+                        .parse()
+                        .map_err(|err: ParseError| err.within(s, direction))
+                })
+                .collect::<Result<_, _>>()?,
+            current_index: 0,
+        })
+    }
+}
+
+impl Iterator for Directions {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.backing.len() == self.current_index {
+            self.current_index = 0;
+        };
+        let result = self.backing.get(self.current_index).unwrap();
+        self.current_index += 1;
+        Some(result.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Maps(HashMap<String, (String, String)>);
+
+impl FromStr for Maps {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nodes = parse_lines(s, |line| {
+            let missing = |expected| {
+                ParseError::new(InputLocation::of(line, line), ErrorKind::Missing(expected))
+            };
+            let (node_name, other) = line.split_once(" = ").ok_or_else(|| missing(" = "))?;
+            let (left, right) = other
+                .trim_matches(|c| c == '(' || c == ')')
+                .split_once(",")
+                .ok_or_else(|| missing(","))?;
+            Ok((
+                node_name.trim().to_string(),
+                (left.trim().to_string(), right.trim().to_string()),
+            ))
+        })?;
+        Ok(Maps(nodes.into_iter().collect()))
+    }
+}
+
+impl Maps {
+    fn get_next(&self, node: &str, direction: &Direction) -> String {
+        let (left, right) = self.0.get(node).unwrap();
+        match direction {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+        .to_string()
+    }
+}
+
+/// How many steps following the directions takes from `AAA` to `ZZZ`
+pub fn steps_to_zzz(directions: &Directions, maps: &Maps) -> i64 {
+    // print!("parsed {directions:?}, maps: {maps:?}");
+    let mut count = 0;
+    let mut current_node = "AAA".to_string();
+    for direction in directions.clone() {
+        if count % 100_000 == 0 {
+            debug!("at {count}");
+        }
+        current_node = maps.get_next(&current_node, &direction);
+        count += 1;
+        if current_node == "ZZZ" {
+            break;
+        }
+    }
+    count
+}
+
+/// How many steps it takes to be on nodes ending in `Z` from every node ending in
+/// `A` at once, walking all of them a step at a time
+pub fn ghost_steps(directions: &Directions, maps: &Maps) -> i64 {
+    let mut current_nodes: Vec<String> = maps
+        .0
+        .keys()
+        .filter(|k| k.ends_with("A"))
+        .map(|s| s.to_string())
+        .collect();
+    trace!("parsed {directions:?}, maps: {maps:?}");
+    debug!("current nodes are {current_nodes:?}");
+    // for starting_node in current_nodes {
+    //     let mut count = 0;
+    //     let mut moving_node = starting_node.clone();
+    //     for direction in directions.clone() {
+    //         moving_node = maps.get_next(&moving_node, &direction);
+    //         count += 1;
+    //         if moving_node.ends_with("Z") {
+    //             break;
+    //         }
+    //     }
+    //     println!("Got to a Z end on {starting_node} in {count}");
+    // }
+    let mut count = 0;
+    for direction in directions.clone() {
+        if count % 100_000 == 0 {
+            debug!("at {count}");
+        }
+        current_nodes = current_nodes
+            .iter()
+            .map(|node| maps.get_next(node, &direction))
+            .collect();
+        // print!("Current nodes are {current_nodes:?}");
+        count += 1;
+        if current_nodes.iter().all(|n| n.ends_with("Z")) {
+            break;
+        }
+    }
+    count
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = (Directions, Maps);
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1((directions, maps): &Self::Input, _config: &()) -> i64 {
+        steps_to_zzz(directions, maps)
+    }
+
+    fn part2((directions, maps): &Self::Input, _config: &()) -> i64 {
+        ghost_steps(directions, maps)
+    }
+}
+
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(Day8::part1(&parse(s)?, &()))
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(Day8::part2(&parse(s)?, &()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::real_input;
+
+    const SAMPLE_INPUT: &str = r##"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"##;
+
+    const SAMPLE_INPUT_2: &str = r##"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"##;
+
+    const SAMPLE_INPUT_3: &str = r##"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"##;
+
+    #[test]
+    fn test_given_1() {
+        assert_eq!(part1(SAMPLE_INPUT), Ok(2))
+    }
+
+    #[test]
+    fn test_full_1() {
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(15517))
+    }
+
+    #[test]
+    fn test_given_1_2() {
+        assert_eq!(part1(SAMPLE_INPUT_3), Ok(6))
+    }
+
+    #[test]
+    fn test_given_2() {
+        assert_eq!(part2(SAMPLE_INPUT_2), Ok(6))
+    }
+
+    #[test]
+    fn test_unknown_direction() {
+        let err = part1("LRX\n\nAAA = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(0, 2));
+        assert_eq!(err.kind, ErrorKind::UnknownDirection("X".to_string()));
+    }
+
+    #[test]
+    fn test_bad_node() {
+        let err = part1("L\n\nAAA = (ZZZ, ZZZ)\nZZZ (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!(err.to_string(), r#"line 4, column 1: missing " = ""#);
+    }
+}
@@ -2,7 +2,10 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use mylib::History;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = include_str!("../input.txt");
+    let Some(input) = aoc_common::real_input!() else {
+        return;
+    };
+    let input = input.as_str();
     let parsed: Vec<History> = input.lines().map(|l| l.parse().unwrap()).collect();
     c.bench_function("part 1", |b| b.iter(|| mylib::part1(black_box(input))));
     c.bench_function("part 2", |b| b.iter(|| mylib::part2(black_box(input))));
@@ -1,16 +1,37 @@
-use std::{convert::Infallible, str::FromStr};
+use aoc_common::{parse, parse_lines, Solution};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidNumber(String),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber(token) => write!(f, "{token:?} isn't a number"),
+        }
+    }
+}
+
+impl From<parse::InvalidNumber> for ErrorKind {
+    fn from(parse::InvalidNumber(token): parse::InvalidNumber) -> Self {
+        ErrorKind::InvalidNumber(token)
+    }
+}
+
+pub type ParseError = aoc_common::ParseError<ErrorKind>;
 
 pub struct History(Vec<i64>);
 
 impl FromStr for History {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values: Vec<i64> = s
-            .trim()
-            .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
+        let values = parse::numbers(s)?;
         Ok(History(values))
     }
 }
@@ -27,7 +48,7 @@ impl History {
     }
 
     #[inline]
-    fn next_value_for_row(prev_row_next_value: i64, row: &Vec<i64>) -> i64 {
+    fn next_value_for_row(prev_row_next_value: i64, row: &[i64]) -> i64 {
         row.last().unwrap() + prev_row_next_value
     }
 
@@ -35,11 +56,12 @@ impl History {
         self.produce_history()
             .iter()
             .rev()
+            .map(Vec::as_slice)
             .fold(0, Self::next_value_for_row)
     }
 
     #[inline]
-    fn previous_value_for_row(prev_row_prev_value: i64, row: &Vec<i64>) -> i64 {
+    fn previous_value_for_row(prev_row_prev_value: i64, row: &[i64]) -> i64 {
         row.first().unwrap() - prev_row_prev_value
     }
 
@@ -47,51 +69,83 @@ impl History {
         self.produce_history()
             .iter()
             .rev()
+            .map(Vec::as_slice)
             .fold(0, Self::previous_value_for_row)
     }
 }
 
-pub fn part1(s: &str) -> i64 {
-    s.lines()
-        .map(|line| line.parse::<History>().unwrap())
-        .map(|history| history.next_value())
-        .sum()
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<History>;
+    type Config = ();
+    type Output = i64;
+    type Error = ParseError;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(s, str::parse)
+    }
+
+    fn part1(histories: &Self::Input, _config: &()) -> i64 {
+        histories.iter().map(|history| history.next_value()).sum()
+    }
+
+    fn part2(histories: &Self::Input, _config: &()) -> i64 {
+        histories
+            .iter()
+            .map(|history| history.previous_value())
+            .sum()
+    }
 }
 
-pub fn part2(s: &str) -> i64 {
-    s.lines()
-        .map(|line| line.parse::<History>().unwrap())
-        .map(|history| history.previous_value())
-        .sum()
+pub fn part1(s: &str) -> Result<i64, ParseError> {
+    Ok(Day9::part1(&Day9::parse(s)?, &()))
+}
+
+pub fn part2(s: &str) -> Result<i64, ParseError> {
+    Ok(Day9::part2(&Day9::parse(s)?, &()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::{real_input, InputLocation};
 
     const SAMPLE_INPUT: &str = r##"0 3 6 9 12 15
 1 3 6 10 15 21
 10 13 16 21 30 45"##;
 
-    const REAL_INPUT: &str = include_str!("../input.txt");
-
     #[test]
     fn test_given_1() {
-        assert_eq!(part1(SAMPLE_INPUT), 114)
+        assert_eq!(part1(SAMPLE_INPUT), Ok(114))
     }
 
     #[test]
     fn test_given_2() {
-        assert_eq!(part2(SAMPLE_INPUT), 2)
+        assert_eq!(part2(SAMPLE_INPUT), Ok(2))
+    }
+
+    #[test]
+    fn test_bad_number() {
+        let err = part1("0 3 6\n1 3 x").unwrap_err();
+        assert_eq!(err.location, InputLocation::new(1, 4));
+        assert_eq!(err.kind, ErrorKind::InvalidNumber("x".to_string()));
     }
 
     #[test]
     fn test_real_1() {
-        assert_eq!(part1(REAL_INPUT), 1861775706)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part1(&input), Ok(1861775706))
     }
 
     #[test]
     fn test_real_2() {
-        assert_eq!(part2(REAL_INPUT), 1082)
+        let Some(input) = real_input!() else {
+            return;
+        };
+        assert_eq!(part2(&input), Ok(1082))
     }
 }
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Day 1 reads the input as it solves, so both parts have to cope with anything
+fuzz_target!(|input: &str| {
+    let _ = day1::part1(input);
+    let _ = day1::part2(input, &day1::WordMap::default());
+});